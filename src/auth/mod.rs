@@ -1 +1,2 @@
+pub mod pkce;
 pub mod server;
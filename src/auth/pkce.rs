@@ -0,0 +1,36 @@
+use base64::{engine::general_purpose, Engine};
+use sha2::{Digest, Sha256};
+
+use crate::utils::random::get_random_string;
+
+const CODE_VERIFIER_LENGTH: usize = 64;
+
+#[derive(Debug, Clone)]
+pub struct Pkce {
+    pub code_verifier: String,
+    pub code_challenge: String,
+}
+
+impl Pkce {
+    pub fn new() -> Self {
+        let code_verifier = get_random_string(CODE_VERIFIER_LENGTH);
+        let code_challenge = Self::get_code_challenge(&code_verifier);
+
+        Self {
+            code_verifier,
+            code_challenge,
+        }
+    }
+
+    pub fn get_code_challenge(code_verifier: &str) -> String {
+        let hash = Sha256::digest(code_verifier.as_bytes());
+
+        general_purpose::URL_SAFE_NO_PAD.encode(hash)
+    }
+}
+
+impl Default for Pkce {
+    fn default() -> Self {
+        Self::new()
+    }
+}
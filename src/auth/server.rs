@@ -1,26 +1,45 @@
 use std::{
-    sync::Arc,
+    net::{self, IpAddr},
     thread::{self, JoinHandle},
 };
 
 use axum::{extract::Query, response::Html, routing::get, serve, Router};
+use color_eyre::eyre::eyre;
+use log::error;
+use reqwest::Url;
 use serde::Deserialize;
 use tokio::{
     net::TcpListener,
     runtime::Runtime,
-    sync::oneshot::{self, Sender},
+    sync::{
+        mpsc::{self, UnboundedReceiver},
+        oneshot::{self, Sender},
+    },
 };
 
-use crate::core::{app::AppResult, config::Config};
+use crate::{
+    core::{app::AppResult, config::Config, spotify::client::SpotifyClient},
+    utils::string::{escape_html, to_script_string},
+};
 
 #[derive(Deserialize)]
 struct CallbackQuery {
-    code: String,
+    code: Option<String>,
+    error: Option<String>,
+    state: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum AuthCallback {
+    Code(String),
+    Error(String),
+    StateMismatch,
 }
 
 pub struct AuthServer {
     pub thread: Option<JoinHandle<()>>,
     pub sender: Option<Sender<()>>,
+    pub receiver: Option<UnboundedReceiver<AuthCallback>>,
 }
 
 impl Default for AuthServer {
@@ -28,142 +47,53 @@ impl Default for AuthServer {
         Self {
             thread: None,
             sender: None,
+            receiver: None,
         }
     }
 }
 
 impl AuthServer {
-    pub fn start(&mut self, config: &Config) -> AppResult<()> {
+    pub fn start(&mut self, spotify_client: &SpotifyClient) -> AppResult<()> {
+        self.stop()?;
+
+        let address = Self::get_callback_address(&spotify_client.config)?;
+        let std_listener = match net::TcpListener::bind(&address) {
+            Ok(listener) => listener,
+            Err(bind_error) => {
+                let error_message = format!(
+                    "Failed to start the auth callback server on {}: {}",
+                    address, bind_error
+                );
+
+                error!("{}", error_message);
+                return Err(eyre!(error_message));
+            }
+        };
+
+        std_listener.set_nonblocking(true)?;
+
         let (tx, rx) = oneshot::channel::<()>();
+        let (callback_tx, callback_rx) = mpsc::unbounded_channel::<AuthCallback>();
 
         self.sender = Some(tx);
+        self.receiver = Some(callback_rx);
 
         let rt = Runtime::new()?;
-        let host = Arc::new(Self::get_host_from_redirect_url(
-            &config.redirect_uri.clone().unwrap_or("".to_string()),
-        ));
+        let expected_state = spotify_client.state.clone();
 
         let thread = thread::spawn(move || {
             rt.block_on(async {
-                let handle_callback = |Query(query): Query<CallbackQuery>| async move {
-                    let code = &query.code;
-                    let html = format!(
-                        "<!DOCTYPE html>
-                        <html>
-                            <head>
-                                <title>Spotify Client TUI</title>
-                                <style>
-                                    html {{
-                                        box-sizing: border-box;
-                                        font-size: 16px;
-                                    }}
-
-                                    *, *:before, *:after {{
-                                        box-sizing: inherit;
-                                    }}
-
-                                    body, h1, h2, h3, h4, h5, h6, p, ol, ul {{
-                                        margin: 0;
-                                        padding: 0;
-                                        font-weight: normal;
-                                    }}
-
-                                    ol, ul {{
-                                        list-style: none;
-                                    }}
-
-                                    img {{
-                                        max-width: 100%;
-                                        height: auto;
-                                    }}
-
-                                    body {{
-                                        background: #343633;
-                                    }}
-
-                                    h1 {{
-                                        color: #45B69C;
-                                        font-size: 60px;
-                                        margin-bottom: 20px;
-                                    }}
-
-                                    h2 {{
-                                        color: white;
-                                    }}
-
-                                    #copied-title {{
-                                        color: #45B69C; 
-                                        margin-top: 40px;
-                                        display: none;
-                                    }}
-
-                                    button {{
-                                        width: max-content;
-                                        font-size: 20px;
-                                        padding: 10px;
-                                        color: #343633;
-                                        font-weight: bold;
-                                        border-radius: 10px;
-                                        background: #45B69C;
-                                        border: none;
-                                    }}
-
-                                    .container {{
-                                        width: 100%;
-                                        height: 100vh; 
-                                        display: flex;
-                                        flex-direction: column;
-                                        justify-content: center;
-                                        align-items: center;
-                                    }}
-
-                                    .code {{
-                                        width: 50%;
-                                        overflow: hidden;
-                                        word-break: break-all;
-                                        background: #7293A0;
-                                        color: white;
-                                        padding: 30px;
-                                        margin-top: 40px;
-                                        margin-bottom: 20px;
-                                        border-radius: 10px;
-                                    }}
-                                </style>
-                            </head>
-                            <body>
-                                <div class=\"container\">
-                                    <h1>Spotify Client TUI</h1>
-                                    <h2>Copy this authentication code to the terminal application in order to sign in.</h2>
-                                    <h3 id=\"copied-title\">Copied to clipboard!</h3>
-
-                                    <div class=\"code\">
-                                        <h2>{}</h2>
-                                    </div>
-
-                                   <button id=\"copy-button\">Copy to Clipboard</button>
-
-                                    <script>
-                                        document.getElementById('copy-button').addEventListener('click', function() {{
-                                            navigator.clipboard.writeText('{}').then(() => {{
-                                               document.getElementById('copied-title').style.display = 'block'; 
-                                            }});
-                                        }});
-                                    </script>
-                                </div>
-                            </body>
-                        </html>
-                    ",
-                        code,
-                        code
-                    );
-
-                    Html(html)
+                let handle_callback = move |Query(query): Query<CallbackQuery>| async move {
+                    let callback = Self::get_callback(query, &expected_state);
+
+                    callback_tx.send(callback.clone()).ok();
+
+                    Html(Self::get_callback_html(&callback))
                 };
 
                 let router: Router = Router::new().route("/callback", get(handle_callback));
 
-                let listener = TcpListener::bind(&*host)
-                    .await
+                let listener = TcpListener::from_std(std_listener)
                     .expect("failed to create listener for auth server");
 
                 serve(listener, router)
@@ -187,20 +117,201 @@ impl AuthServer {
         if let Some(thread) = self.thread.take() {
             let _ = thread.join();
         }
+
+        self.receiver = None;
+
         Ok(())
     }
 
-    fn get_host_from_redirect_url(redirect_url: &str) -> String {
-        let mut host = String::new();
+    pub fn try_recv(&mut self) -> Option<AuthCallback> {
+        match self.receiver.as_mut() {
+            Some(receiver) => receiver.try_recv().ok(),
+            None => None,
+        }
+    }
+
+    fn get_callback(query: CallbackQuery, expected_state: &str) -> AuthCallback {
+        if query.state.as_deref() != Some(expected_state) {
+            return AuthCallback::StateMismatch;
+        }
 
-        if let Some(start) = redirect_url.find("://") {
-            let start_index = start + 3;
-            if let Some(end) = redirect_url[start_index..].find("/") {
-                host = redirect_url[start_index..start_index + end].to_string();
+        match (query.code, query.error) {
+            (_, Some(error)) if error == "access_denied" => {
+                AuthCallback::Error("Access to your Spotify account was denied.".to_string())
+            }
+            (_, Some(error)) => {
+                AuthCallback::Error(format!("Spotify returned an error: {}", error))
+            }
+            (Some(code), None) => AuthCallback::Code(code),
+            (None, None) => {
+                AuthCallback::Error("Spotify did not return an authentication code.".to_string())
             }
         }
+    }
 
-        host
+    fn get_callback_html(callback: &AuthCallback) -> String {
+        let content = match callback {
+            AuthCallback::Code(code) => format!(
+                "<h2>You are signed in. You can close this tab and return to the terminal application.</h2>
+                <h2>If the terminal application did not sign in automatically, copy this authentication code to it.</h2>
+                <h3 id=\"copied-title\">Copied to clipboard!</h3>
+
+                <div class=\"code\">
+                    <h2>{}</h2>
+                </div>
+
+                <button id=\"copy-button\">Copy to Clipboard</button>
+
+                <script>
+                    document.getElementById('copy-button').addEventListener('click', function() {{
+                        navigator.clipboard.writeText({}).then(() => {{
+                            document.getElementById('copied-title').style.display = 'block';
+                        }});
+                    }});
+                </script>",
+                escape_html(code),
+                to_script_string(code)
+            ),
+            AuthCallback::Error(message) => format!(
+                "<h2 class=\"error\">{}</h2>
+                <h2>Return to the terminal application to try again.</h2>",
+                escape_html(message)
+            ),
+            AuthCallback::StateMismatch => "<h2 class=\"error\">This login response does not belong to the login in progress and was ignored.</h2>
+                <h2>Finish logging in from the link shown in the terminal application.</h2>"
+                .to_string(),
+        };
+
+        format!(
+            "<!DOCTYPE html>
+            <html>
+                <head>
+                    <title>Spotify Client TUI</title>
+                    <style>
+                        html {{
+                            box-sizing: border-box;
+                            font-size: 16px;
+                        }}
+
+                        *, *:before, *:after {{
+                            box-sizing: inherit;
+                        }}
+
+                        body, h1, h2, h3, h4, h5, h6, p, ol, ul {{
+                            margin: 0;
+                            padding: 0;
+                            font-weight: normal;
+                        }}
+
+                        ol, ul {{
+                            list-style: none;
+                        }}
+
+                        img {{
+                            max-width: 100%;
+                            height: auto;
+                        }}
+
+                        body {{
+                            background: #343633;
+                        }}
+
+                        h1 {{
+                            color: #45B69C;
+                            font-size: 60px;
+                            margin-bottom: 20px;
+                        }}
+
+                        h2 {{
+                            color: white;
+                        }}
+
+                        .error {{
+                            color: #E05263;
+                        }}
+
+                        #copied-title {{
+                            color: #45B69C; 
+                            margin-top: 40px;
+                            display: none;
+                        }}
+
+                        button {{
+                            width: max-content;
+                            font-size: 20px;
+                            padding: 10px;
+                            color: #343633;
+                            font-weight: bold;
+                            border-radius: 10px;
+                            background: #45B69C;
+                            border: none;
+                        }}
+
+                        .container {{
+                            width: 100%;
+                            height: 100vh; 
+                            display: flex;
+                            flex-direction: column;
+                            justify-content: center;
+                            align-items: center;
+                        }}
+
+                        .code {{
+                            width: 50%;
+                            overflow: hidden;
+                            word-break: break-all;
+                            background: #7293A0;
+                            color: white;
+                            padding: 30px;
+                            margin-top: 40px;
+                            margin-bottom: 20px;
+                            border-radius: 10px;
+                        }}
+                    </style>
+                </head>
+                <body>
+                    <div class=\"container\">
+                        <h1>Spotify Client TUI</h1>
+                        {}
+                    </div>
+                </body>
+            </html>",
+            content
+        )
+    }
+
+    fn get_callback_address(config: &Config) -> AppResult<String> {
+        let redirect_uri = config.redirect_uri.clone().unwrap_or_default();
+        let url = Url::parse(&redirect_uri)?;
+        let host = url.host_str().unwrap_or_default();
+
+        if host.is_empty() {
+            let error_message = format!("Redirect URI `{}` has no host.", redirect_uri);
+
+            error!("{}", error_message);
+            return Err(eyre!(error_message));
+        }
+
+        let is_loopback = match host.trim_matches(['[', ']']).parse::<IpAddr>() {
+            Ok(ip) => ip.is_loopback(),
+            Err(_) => host.eq_ignore_ascii_case("localhost"),
+        };
+
+        if !is_loopback && !config.allow_non_loopback_callback.unwrap_or(false) {
+            let error_message = format!(
+                "Refusing to start the auth callback server on non-loopback host `{}`. Use a redirect URI on 127.0.0.1 or set `allow_non_loopback_callback` in config.json.",
+                host
+            );
+
+            error!("{}", error_message);
+            return Err(eyre!(error_message));
+        }
+
+        Ok(format!(
+            "{}:{}",
+            host,
+            url.port_or_known_default().unwrap_or(80)
+        ))
     }
 }
 
@@ -214,3 +325,54 @@ impl Drop for AuthServer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_query(code: Option<&str>, error: Option<&str>, state: Option<&str>) -> CallbackQuery {
+        CallbackQuery {
+            code: code.map(str::to_string),
+            error: error.map(str::to_string),
+            state: state.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn mismatched_state_is_ignored() {
+        let callback =
+            AuthServer::get_callback(get_query(Some("code"), None, Some("forged")), "expected");
+
+        assert!(matches!(callback, AuthCallback::StateMismatch));
+    }
+
+    #[test]
+    fn matching_state_returns_code() {
+        let callback =
+            AuthServer::get_callback(get_query(Some("code"), None, Some("expected")), "expected");
+
+        assert!(matches!(callback, AuthCallback::Code(code) if code == "code"));
+    }
+
+    #[test]
+    fn callback_html_escapes_code() {
+        let code = "x');</script><script>alert(1)//";
+        let html = AuthServer::get_callback_html(&AuthCallback::Code(code.to_string()));
+
+        assert!(!html.contains("<script>alert(1)"));
+        assert!(!html.contains("</script><script>"));
+        assert!(html.contains("writeText(\"x');\\u003c/script\\u003e"));
+    }
+
+    #[test]
+    fn callback_html_escapes_error() {
+        let callback = AuthServer::get_callback(
+            get_query(None, Some("<img src=x onerror=alert(1)>"), Some("expected")),
+            "expected",
+        );
+        let html = AuthServer::get_callback_html(&callback);
+
+        assert!(!html.contains("<img"));
+        assert!(html.contains("&lt;img src=x onerror=alert(1)&gt;"));
+    }
+}
@@ -3,7 +3,10 @@ use ratatui::{crossterm::event::KeyEvent, layout::Rect, Frame};
 use crate::core::{
     app::{App, AppResult},
     message::Message,
-    spotify::{album::Album, artist::Artist, device::Device, now_playing::NowPlaying, song::Song},
+    spotify::{
+        album::Album, artist::Artist, device::Device, library::Library, now_playing::NowPlaying,
+        playlist::Playlist, queue::Queue, search::Search, song::Song,
+    },
 };
 
 pub trait Component: ComponentClone {
@@ -39,6 +42,26 @@ pub trait Component: ComponentClone {
     fn get_artist(&mut self) -> Option<&mut Artist> {
         None
     }
+
+    fn get_search(&mut self) -> Option<&mut Search> {
+        None
+    }
+
+    fn get_queue(&mut self) -> Option<&mut Queue> {
+        None
+    }
+
+    fn get_library(&mut self) -> Option<&mut Library> {
+        None
+    }
+
+    fn get_playlist(&mut self) -> Option<&mut Playlist> {
+        None
+    }
+
+    fn get_components(&mut self) -> Vec<&mut dyn Component> {
+        vec![]
+    }
 }
 
 pub trait ComponentClone {
@@ -1,2 +1,3 @@
 pub mod form;
 pub mod text_input;
+pub mod toggle_input;
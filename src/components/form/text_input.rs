@@ -175,7 +175,7 @@ impl Component for TextInput {
             KeyCode::Right => {
                 if self.mode == FormMode::Insert
                     && self.is_focused
-                    && self.value.len() - 1 >= self.cursor_position
+                    && self.cursor_position < self.value.len()
                 {
                     self.cursor_position = self.cursor_position + 1;
                 }
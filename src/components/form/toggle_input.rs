@@ -0,0 +1,91 @@
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Rect},
+    Frame,
+};
+
+use crate::{
+    components::Component,
+    core::{
+        app::{App, AppResult},
+        message::Message,
+    },
+    widgets::{block::create_titled_block, paragraph::create_paragraph_with_block},
+};
+
+use super::form::{FormMode, Input, InputValue};
+
+#[derive(Clone)]
+pub struct ToggleInput {
+    pub title: String,
+    pub value: bool,
+    pub mode: FormMode,
+    pub is_focused: bool,
+    pub area: Option<Rect>,
+}
+
+impl ToggleInput {
+    pub fn new(title: &str, value: bool) -> Self {
+        Self {
+            title: title.into(),
+            value,
+            mode: FormMode::Normal,
+            is_focused: false,
+            area: None,
+        }
+    }
+}
+
+impl Input for ToggleInput {
+    fn get_is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn set_is_focused(&mut self, focus: bool) {
+        self.is_focused = focus;
+    }
+
+    fn get_mode(&self) -> FormMode {
+        self.mode.clone()
+    }
+
+    fn set_mode(&mut self, mode: FormMode) {
+        self.mode = mode;
+    }
+
+    fn get_value(&self) -> InputValue {
+        InputValue::Boolean(self.value)
+    }
+}
+
+impl Component for ToggleInput {
+    fn view(&mut self, _: &App, frame: &mut Frame) {
+        let text = match self.value {
+            true => "[x] Yes (space to toggle)",
+            false => "[ ] No (space to toggle)",
+        };
+
+        if let Some(area) = self.area {
+            let block = create_titled_block(&self.title, Alignment::Left, self.get_color());
+            let paragraph = create_paragraph_with_block(text, block, self.get_color());
+
+            frame.render_widget(paragraph, area);
+        }
+    }
+
+    fn handle_key_press(&mut self, _app: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
+        if key.code == KeyCode::Char(' ') && self.is_focused {
+            self.value = !self.value;
+        }
+
+        Ok(None)
+    }
+
+    fn tick(&mut self, _: &mut App) -> AppResult<Option<Message>> {
+        Ok(None)
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use ratatui::crossterm::event::{KeyCode, KeyEvent};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Style};
@@ -22,6 +24,7 @@ pub struct List {
     start_index: usize,
     end_index: usize,
     items_changed: bool,
+    prefetched_range: Range<usize>,
 }
 
 impl Default for List {
@@ -36,6 +39,7 @@ impl Default for List {
             start_index: 0,
             end_index: 0,
             items_changed: false,
+            prefetched_range: 0..0,
         }
     }
 }
@@ -52,6 +56,7 @@ impl List {
             start_index: 0,
             end_index: 0,
             items_changed: true,
+            prefetched_range: 0..0,
         }
     }
 
@@ -68,6 +73,11 @@ impl List {
     pub fn set_items(&mut self, items: Vec<NameAndId>) {
         self.items = items;
         self.items_changed = true;
+        self.prefetched_range = 0..0;
+    }
+
+    pub fn append_items(&mut self, items: Vec<NameAndId>) {
+        self.items.extend(items);
     }
 
     pub fn get_active_item(&self) -> NameAndId {
@@ -77,6 +87,35 @@ impl List {
         }
     }
 
+    pub fn get_prefetch_ids(&mut self) -> Option<Vec<String>> {
+        let page_size = self.max_items as usize;
+        let end_index = self.end_index.min(self.items.len());
+
+        if page_size == 0 || self.start_index >= end_index {
+            return None;
+        }
+
+        if self.prefetched_range.start <= self.start_index && end_index <= self.prefetched_range.end
+        {
+            return None;
+        }
+
+        let prefetch_end = (end_index + page_size).min(self.items.len());
+
+        self.prefetched_range = self.start_index..prefetch_end;
+
+        let ids: Vec<String> = self.items[self.prefetched_range.clone()]
+            .iter()
+            .map(|item| item.1.clone())
+            .filter(|id| !id.is_empty())
+            .collect();
+
+        match ids.is_empty() {
+            true => None,
+            false => Some(ids),
+        }
+    }
+
     fn move_selection_down(&mut self) {
         if self.active_index >= self.end_index - 1 {
             self.start_index = self.start_index + 1;
@@ -167,6 +206,10 @@ impl Component for List {
     }
 
     fn handle_key_press(&mut self, _: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
+        if self.items.is_empty() {
+            return Ok(None);
+        }
+
         match key.code {
             KeyCode::Char('j') => {
                 self.move_selection_down();
@@ -2,6 +2,7 @@ use ratatui::{
     crossterm::event::KeyEvent,
     layout::{Constraint, Layout},
     style::Color,
+    widgets::Clear,
     Frame,
 };
 
@@ -52,6 +53,7 @@ impl Component for Prompt {
             .constraints([Constraint::Min(5), Constraint::Max(10), Constraint::Min(1)])
             .split(container_area);
 
+        frame.render_widget(Clear, container_area);
         frame.render_widget(container, container_area);
         frame.render_widget(prompt_paragraph, chunks[0]);
         frame.render_widget(options_paragraph, chunks[2]);
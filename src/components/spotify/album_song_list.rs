@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use ratatui::{
     crossterm::event::{KeyCode, KeyEvent},
     layout::{Constraint, Direction, Layout, Rect},
@@ -13,6 +15,7 @@ use crate::{
         message::Message,
         spotify::album::Album,
     },
+    screens::add_to_playlist::AddToPlaylistScreen,
 };
 
 #[derive(Debug, Clone)]
@@ -25,6 +28,7 @@ pub struct AlbumSongList {
     song_start_index: usize,
     song_end_index: usize,
     album_changed: bool,
+    prefetched_range: Range<usize>,
 }
 
 impl Default for AlbumSongList {
@@ -38,6 +42,7 @@ impl Default for AlbumSongList {
             song_start_index: 0,
             song_end_index: 0,
             album_changed: false,
+            prefetched_range: 0..0,
         }
     }
 }
@@ -60,6 +65,7 @@ impl AlbumSongList {
         self.song_start_index = 0;
         self.active_song_index = 0;
         self.album_changed = true;
+        self.prefetched_range = 0..0;
     }
 
     pub fn get_active_song_id(&self) -> String {
@@ -70,6 +76,36 @@ impl AlbumSongList {
         "".to_string()
     }
 
+    pub fn get_prefetch_song_ids(&mut self) -> Option<Vec<String>> {
+        let page_size = self.max_songs_shown as usize;
+        let end_index = self.song_end_index.min(self.album.songs.len());
+
+        if page_size == 0 || self.song_start_index >= end_index {
+            return None;
+        }
+
+        if self.prefetched_range.start <= self.song_start_index
+            && end_index <= self.prefetched_range.end
+        {
+            return None;
+        }
+
+        let prefetch_end = (end_index + page_size).min(self.album.songs.len());
+
+        self.prefetched_range = self.song_start_index..prefetch_end;
+
+        let song_ids: Vec<String> = self.album.songs[self.prefetched_range.clone()]
+            .iter()
+            .map(|song| song.1.clone())
+            .filter(|id| !id.is_empty())
+            .collect();
+
+        match song_ids.is_empty() {
+            true => None,
+            false => Some(song_ids),
+        }
+    }
+
     fn get_song_style(&self, index: usize) -> Style {
         let mut style = Style::default().fg(Color::Green);
 
@@ -180,6 +216,12 @@ impl Component for AlbumSongList {
                     album_id,
                 }))
             }
+            KeyCode::Char('a') if !self.album.is_empty() => Ok(Some(Message::AddToQueue {
+                song_id: self.get_active_song_id(),
+            })),
+            KeyCode::Char('A') if !self.album.is_empty() => Ok(Some(Message::ChangeScreen {
+                new_screen: Box::new(AddToPlaylistScreen::new(self.get_active_song_id())),
+            })),
             _ => Ok(None),
         }
     }
@@ -20,6 +20,7 @@ pub struct ArtistInfo {
     area: Rect,
     now_playing: NowPlaying,
     artist: Artist,
+    artist_id: Option<String>,
 }
 
 impl Default for ArtistInfo {
@@ -28,13 +29,30 @@ impl Default for ArtistInfo {
             area: Rect::default(),
             now_playing: NowPlaying::default(),
             artist: Artist::default(),
+            artist_id: None,
+        }
+    }
+}
+
+impl ArtistInfo {
+    pub fn new(artist_id: String) -> Self {
+        Self {
+            artist_id: Some(artist_id),
+            ..Self::default()
+        }
+    }
+
+    fn get_artist_id(&self) -> Option<String> {
+        match &self.artist_id {
+            Some(artist_id) => Some(artist_id.clone()),
+            None => self.now_playing.artist_ids.first().cloned(),
         }
     }
 }
 
 impl Component for ArtistInfo {
     fn view(&mut self, app: &App, frame: &mut Frame) {
-        if self.now_playing.is_empty() || self.artist.is_empty() {
+        if self.artist.is_empty() {
             let mut loading = Loading::default();
 
             loading.set_area(&self.area);
@@ -76,13 +94,15 @@ impl Component for ArtistInfo {
     }
 
     fn tick(&mut self, _: &mut App) -> AppResult<Option<Message>> {
-        if !self.now_playing.is_empty() && self.now_playing.artist_ids[0] != self.artist.id {
-            self.artist.id = self.now_playing.artist_ids[0].clone();
+        if let Some(artist_id) = self.get_artist_id() {
+            if artist_id != self.artist.id {
+                self.artist.id = artist_id;
 
-            return Ok(Some(Message::RefreshArtist));
+                return Ok(Some(Message::RefreshArtist));
+            }
         }
 
-        Ok(Some(Message::RefreshNowPlaying))
+        Ok(None)
     }
 
     fn handle_key_press(&mut self, _: &mut App, _: KeyEvent) -> AppResult<Option<Message>> {
@@ -11,6 +11,7 @@ use crate::{
         message::Message,
         spotify::{album::Album, artist::Artist, now_playing::NowPlaying, song::Song},
     },
+    screens::add_to_playlist::AddToPlaylistScreen,
     widgets::block::create_block,
     App, AppResult,
 };
@@ -33,6 +34,7 @@ pub struct ArtistSingles {
     song_list: List,
     info_window: SongInfoWindow,
     active_list_type: ListType,
+    artist_id: Option<String>,
 }
 
 impl Default for ArtistSingles {
@@ -50,6 +52,23 @@ impl Default for ArtistSingles {
             song_list,
             info_window: SongInfoWindow::default(),
             active_list_type: ListType::Single,
+            artist_id: None,
+        }
+    }
+}
+
+impl ArtistSingles {
+    pub fn new(artist_id: String) -> Self {
+        Self {
+            artist_id: Some(artist_id),
+            ..Self::default()
+        }
+    }
+
+    fn get_artist_id(&self) -> Option<String> {
+        match &self.artist_id {
+            Some(artist_id) => Some(artist_id.clone()),
+            None => self.now_playing.artist_ids.first().cloned(),
         }
     }
 }
@@ -86,9 +105,9 @@ impl Component for ArtistSingles {
     }
 
     fn tick(&mut self, _: &mut App) -> AppResult<Option<Message>> {
-        if !self.now_playing.is_empty() {
-            if self.now_playing.artist_ids[0] != self.artist.id {
-                self.artist.id = self.now_playing.artist_ids[0].clone();
+        if let Some(artist_id) = self.get_artist_id() {
+            if artist_id != self.artist.id {
+                self.artist.id = artist_id;
 
                 return Ok(Some(Message::RefreshArtist));
             }
@@ -122,7 +141,17 @@ impl Component for ArtistSingles {
             return Ok(Some(Message::RefreshSong));
         }
 
-        Ok(Some(Message::RefreshNowPlaying))
+        if let Some(album_ids) = self.single_list.get_prefetch_ids() {
+            return Ok(Some(Message::PrefetchAlbums { album_ids }));
+        }
+
+        if self.active_list_type == ListType::Song {
+            if let Some(song_ids) = self.song_list.get_prefetch_ids() {
+                return Ok(Some(Message::PrefetchSongs { song_ids }));
+            }
+        }
+
+        Ok(None)
     }
 
     fn handle_key_press(&mut self, app: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
@@ -164,6 +193,30 @@ impl Component for ArtistSingles {
                     }));
                 }
             },
+            KeyCode::Char('a')
+                if self.active_list_type == ListType::Song && !self.song_list.items.is_empty() =>
+            {
+                return Ok(Some(Message::AddToQueue {
+                    song_id: self.song_list.get_active_item().1,
+                }));
+            }
+            KeyCode::Char('f')
+                if self.active_list_type == ListType::Song && !self.info_window.song.is_empty() =>
+            {
+                return Ok(Some(Message::ToggleSavedSong));
+            }
+            KeyCode::Char('F') if !self.album.is_empty() => {
+                return Ok(Some(Message::ToggleSavedAlbum));
+            }
+            KeyCode::Char('A')
+                if self.active_list_type == ListType::Song && !self.song_list.items.is_empty() =>
+            {
+                return Ok(Some(Message::ChangeScreen {
+                    new_screen: Box::new(AddToPlaylistScreen::new(
+                        self.song_list.get_active_item().1,
+                    )),
+                }));
+            }
 
             _ => {}
         }
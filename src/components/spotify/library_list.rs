@@ -0,0 +1,165 @@
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Constraint, Layout, Rect},
+    style::Color,
+    Frame,
+};
+
+use crate::{
+    components::{list::List, loading::Loading, Component},
+    core::{
+        message::Message,
+        spotify::library::{Library, LibraryType},
+    },
+    screens::{
+        add_to_playlist::AddToPlaylistScreen,
+        view::{album::ViewAlbumScreen, artist::ViewArtistScreen, playlist::ViewPlaylistScreen},
+    },
+    widgets::{block::create_block, paragraph::create_centered_paragraph},
+    App, AppResult,
+};
+
+#[derive(Clone)]
+pub struct LibraryList {
+    library: Library,
+    list: List,
+    area: Rect,
+}
+
+impl LibraryList {
+    pub fn new(library_type: LibraryType) -> Self {
+        Self {
+            library: Library::new(library_type),
+            list: List::default(),
+            area: Rect::default(),
+        }
+    }
+}
+
+impl Component for LibraryList {
+    fn view(&mut self, app: &App, frame: &mut Frame) {
+        frame.render_widget(create_block(Color::Green), self.area);
+
+        if !self.library.is_loaded {
+            let mut loading = Loading::default();
+
+            loading.set_area(&self.area);
+            loading.view(app, frame);
+            return;
+        }
+
+        if self.library.items.is_empty() {
+            let message = format!(
+                "No {} in your library",
+                self.library.library_type.get_title().to_lowercase()
+            );
+            let paragraph = create_centered_paragraph(&message, Some(Color::Green));
+
+            let chunks = Layout::default()
+                .margin(5)
+                .constraints(vec![Constraint::Min(1)])
+                .split(self.area);
+
+            frame.render_widget(paragraph, chunks[0]);
+            return;
+        }
+
+        self.list.set_area(self.area);
+        self.list.view(app, frame);
+    }
+
+    fn tick(&mut self, _: &mut App) -> AppResult<Option<Message>> {
+        if !self.library.is_loaded {
+            return Ok(Some(Message::RefreshLibrary));
+        }
+
+        if self.library.items != self.list.items {
+            match self.library.items.starts_with(&self.list.items) && !self.list.items.is_empty() {
+                true => {
+                    let new_items = self.library.items[self.list.items.len()..].to_vec();
+
+                    self.list.append_items(new_items);
+                }
+                false => self.list = List::new(self.library.items.clone(), None),
+            }
+        }
+
+        if !self.list.items.is_empty()
+            && self.list.active_index + 1 >= self.list.items.len()
+            && self.library.has_next_page()
+        {
+            return Ok(Some(Message::NextLibraryPage));
+        }
+
+        if let Some(ids) = self.list.get_prefetch_ids() {
+            match self.library.library_type {
+                LibraryType::SavedAlbums => {
+                    return Ok(Some(Message::PrefetchAlbums { album_ids: ids }))
+                }
+                LibraryType::FollowedArtists => {
+                    return Ok(Some(Message::PrefetchArtists { artist_ids: ids }))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn handle_key_press(&mut self, app: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
+        if let Some(message) = self.list.handle_key_press(app, key)? {
+            return Ok(Some(message));
+        }
+
+        if key.code == KeyCode::Char('r') {
+            self.library.is_loaded = false;
+
+            return Ok(None);
+        }
+
+        if self.list.items.is_empty() {
+            return Ok(None);
+        }
+
+        let (_, id) = self.list.get_active_item();
+
+        match key.code {
+            KeyCode::Char('a') if self.library.library_type == LibraryType::SavedTracks => {
+                Ok(Some(Message::AddToQueue { song_id: id }))
+            }
+            KeyCode::Char('A') if self.library.library_type == LibraryType::SavedTracks => {
+                Ok(Some(Message::ChangeScreen {
+                    new_screen: Box::new(AddToPlaylistScreen::new(id)),
+                }))
+            }
+            KeyCode::Enter => match self.library.library_type {
+                LibraryType::SavedTracks => Ok(Some(Message::PlaySongs {
+                    offset: self.list.active_index,
+                    songs: self.list.items.clone(),
+                })),
+                LibraryType::SavedAlbums => Ok(Some(Message::ChangeScreen {
+                    new_screen: Box::new(ViewAlbumScreen::new(id)),
+                })),
+                LibraryType::FollowedArtists => Ok(Some(Message::ChangeScreen {
+                    new_screen: Box::new(ViewArtistScreen::new(id)),
+                })),
+                LibraryType::Playlists => Ok(Some(Message::ChangeScreen {
+                    new_screen: Box::new(ViewPlaylistScreen::new(id)),
+                })),
+            },
+            _ => Ok(None),
+        }
+    }
+
+    fn get_area(&mut self) -> Rect {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = area;
+    }
+
+    fn get_library(&mut self) -> Option<&mut Library> {
+        Some(&mut self.library)
+    }
+}
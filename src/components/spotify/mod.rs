@@ -2,5 +2,8 @@ pub mod album_song_list;
 pub mod artist_albums;
 pub mod artist_info;
 pub mod artist_singles;
+pub mod library_list;
+pub mod playlist_form;
+pub mod search_results;
 pub mod song_info_window;
 pub mod top_songs;
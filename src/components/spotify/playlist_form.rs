@@ -0,0 +1,145 @@
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Constraint, Layout},
+    style::Color,
+    widgets::Clear,
+    Frame,
+};
+
+use crate::{
+    components::{
+        form::{
+            form::{Form, FormMode, Input},
+            text_input::TextInput,
+            toggle_input::ToggleInput,
+        },
+        Component,
+    },
+    core::{
+        app::{App, AppResult},
+        message::Message,
+        spotify::playlist::Playlist,
+    },
+    layout::rect::get_centered_rect,
+    widgets::block::create_titled_block,
+};
+
+#[derive(Clone)]
+pub struct PlaylistForm {
+    form: Form,
+    title: String,
+    is_new: bool,
+}
+
+impl Default for PlaylistForm {
+    fn default() -> Self {
+        Self::new_with_values("New Playlist", "", "", false, true)
+    }
+}
+
+impl PlaylistForm {
+    pub fn edit(playlist: &Playlist) -> Self {
+        Self::new_with_values(
+            "Edit Playlist",
+            &playlist.name,
+            &playlist.description,
+            playlist.public,
+            false,
+        )
+    }
+
+    fn new_with_values(
+        title: &str,
+        name: &str,
+        description: &str,
+        public: bool,
+        is_new: bool,
+    ) -> Self {
+        let mut inputs = Vec::<Box<dyn Input>>::new();
+        let mut name_input = TextInput::new("Name", name, false);
+        let mut description_input = TextInput::new("Description", description, false);
+        let public_input = ToggleInput::new("Public", public);
+
+        name_input.cursor_position = name.len();
+        description_input.cursor_position = description.len();
+
+        inputs.push(Box::new(name_input));
+        inputs.push(Box::new(description_input));
+        inputs.push(Box::new(public_input));
+
+        Self {
+            form: Form::new(inputs),
+            title: title.to_string(),
+            is_new,
+        }
+    }
+
+    pub fn is_typing(&self) -> bool {
+        self.form.mode == FormMode::Insert
+    }
+
+    fn get_name(&self) -> String {
+        self.form.inputs[0].get_value().get_text()
+    }
+
+    fn get_description(&self) -> String {
+        self.form.inputs[1].get_value().get_text()
+    }
+
+    fn get_public(&self) -> bool {
+        self.form.inputs[2].get_value().get_boolean(false)
+    }
+}
+
+impl Component for PlaylistForm {
+    fn view(&mut self, app: &App, frame: &mut Frame) {
+        let rect = get_centered_rect(70, 60, frame.area());
+        let title = format!("{} (i to type, Enter to save, q to cancel)", self.title);
+        let block = create_titled_block(&title, Alignment::Center, Color::Green);
+
+        let chunks = Layout::default()
+            .margin(2)
+            .constraints(vec![
+                Constraint::Max(3),
+                Constraint::Max(3),
+                Constraint::Max(3),
+            ])
+            .split(rect);
+
+        frame.render_widget(Clear, rect);
+        frame.render_widget(block, rect);
+
+        for (i, chunk) in chunks.iter().enumerate() {
+            self.form.inputs[i].set_area(*chunk);
+        }
+
+        self.form.view(app, frame);
+    }
+
+    fn tick(&mut self, app: &mut App) -> AppResult<Option<Message>> {
+        self.form.tick(app)
+    }
+
+    fn handle_key_press(&mut self, app: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
+        if let Some(message) = self.form.handle_key_press(app, key)? {
+            return Ok(Some(message));
+        }
+
+        if key.code != KeyCode::Enter || self.is_typing() || self.get_name().trim().is_empty() {
+            return Ok(None);
+        }
+
+        match self.is_new {
+            true => Ok(Some(Message::CreatePlaylist {
+                name: self.get_name(),
+                description: self.get_description(),
+                public: self.get_public(),
+            })),
+            false => Ok(Some(Message::UpdatePlaylistDetails {
+                name: self.get_name(),
+                description: self.get_description(),
+                public: self.get_public(),
+            })),
+        }
+    }
+}
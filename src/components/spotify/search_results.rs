@@ -0,0 +1,171 @@
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Constraint, Layout, Rect},
+    style::Color,
+    Frame,
+};
+
+use crate::{
+    components::{list::List, loading::Loading, Component},
+    core::{
+        message::Message,
+        spotify::search::{Search, SearchType},
+    },
+    screens::{
+        add_to_playlist::AddToPlaylistScreen,
+        view::{album::ViewAlbumScreen, artist::ViewArtistScreen, playlist::ViewPlaylistScreen},
+    },
+    widgets::{block::create_block, paragraph::create_centered_paragraph},
+    App, AppResult,
+};
+
+#[derive(Clone)]
+pub struct SearchResults {
+    search: Search,
+    list: List,
+    area: Rect,
+}
+
+impl SearchResults {
+    pub fn new(search_type: SearchType) -> Self {
+        Self {
+            search: Search::new(search_type),
+            list: List::default(),
+            area: Rect::default(),
+        }
+    }
+
+    fn view_message(&self, message: &str, frame: &mut Frame) {
+        let paragraph = create_centered_paragraph(message, Some(Color::Green));
+
+        let chunks = Layout::default()
+            .margin(5)
+            .constraints(vec![Constraint::Min(1)])
+            .split(self.area);
+
+        frame.render_widget(paragraph, chunks[0]);
+    }
+}
+
+impl Component for SearchResults {
+    fn view(&mut self, app: &App, frame: &mut Frame) {
+        frame.render_widget(create_block(Color::Green), self.area);
+
+        if self.search.query.is_empty() {
+            self.view_message("Press i to type a search and Enter to run it", frame);
+            return;
+        }
+
+        if !self.search.is_loaded() {
+            let mut loading = Loading::default();
+
+            loading.set_area(&self.area);
+            loading.view(app, frame);
+            return;
+        }
+
+        if self.search.results.is_empty() {
+            let message = format!(
+                "No {} found for \"{}\"",
+                self.search.search_type.get_title().to_lowercase(),
+                self.search.query
+            );
+
+            self.view_message(&message, frame);
+            return;
+        }
+
+        self.list.set_area(self.area);
+        self.list.view(app, frame);
+    }
+
+    fn tick(&mut self, _: &mut App) -> AppResult<Option<Message>> {
+        if self.search.needs_refresh() {
+            return Ok(Some(Message::RefreshSearch));
+        }
+
+        if self.search.results != self.list.items {
+            match self.search.results.starts_with(&self.list.items) && !self.list.items.is_empty() {
+                true => {
+                    let new_items = self.search.results[self.list.items.len()..].to_vec();
+
+                    self.list.append_items(new_items);
+                }
+                false => self.list = List::new(self.search.results.clone(), None),
+            }
+        }
+
+        if !self.list.items.is_empty()
+            && self.list.active_index + 1 >= self.list.items.len()
+            && self.search.has_next_page()
+        {
+            return Ok(Some(Message::NextSearchPage));
+        }
+
+        if let Some(ids) = self.list.get_prefetch_ids() {
+            match self.search.search_type {
+                SearchType::Album => return Ok(Some(Message::PrefetchAlbums { album_ids: ids })),
+                SearchType::Artist => {
+                    return Ok(Some(Message::PrefetchArtists { artist_ids: ids }))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn handle_key_press(&mut self, app: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
+        if let Some(message) = self.list.handle_key_press(app, key)? {
+            return Ok(Some(message));
+        }
+
+        if self.list.items.is_empty() {
+            return Ok(None);
+        }
+
+        let (_, id) = self.list.get_active_item();
+
+        if key.code == KeyCode::Char('a') && self.search.search_type == SearchType::Track {
+            return Ok(Some(Message::AddToQueue { song_id: id }));
+        }
+
+        if key.code == KeyCode::Char('A') && self.search.search_type == SearchType::Track {
+            return Ok(Some(Message::ChangeScreen {
+                new_screen: Box::new(AddToPlaylistScreen::new(id)),
+            }));
+        }
+
+        if key.code != KeyCode::Enter {
+            return Ok(None);
+        }
+
+        match self.search.search_type {
+            SearchType::Track => Ok(Some(Message::PlaySongs {
+                offset: self.list.active_index,
+                songs: self.list.items.clone(),
+            })),
+            SearchType::Album => Ok(Some(Message::ChangeScreen {
+                new_screen: Box::new(ViewAlbumScreen::new(id)),
+            })),
+            SearchType::Artist => Ok(Some(Message::ChangeScreen {
+                new_screen: Box::new(ViewArtistScreen::new(id)),
+            })),
+            SearchType::Playlist => Ok(Some(Message::ChangeScreen {
+                new_screen: Box::new(ViewPlaylistScreen::new(id)),
+            })),
+        }
+    }
+
+    fn get_area(&mut self) -> Rect {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = area;
+    }
+
+    fn get_search(&mut self) -> Option<&mut Search> {
+        Some(&mut self.search)
+    }
+}
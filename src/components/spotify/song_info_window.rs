@@ -46,7 +46,7 @@ impl Component for SongInfoWindow {
             return;
         }
 
-        let song_string = format!("Song: {}", self.song.name);
+        let song_string = format!("Song: {}{}", self.song.name, self.song.get_saved_marker());
         let artists_string = format!("Artists: {}", self.song.get_artists_string());
         let album_string = format!("Album: {}", self.song.album_name);
         let song_length_string = format!("Song Length: {}", self.song.get_song_length_string());
@@ -11,6 +11,7 @@ use crate::{
         message::Message,
         spotify::{artist::Artist, now_playing::NowPlaying, song::Song},
     },
+    screens::add_to_playlist::AddToPlaylistScreen,
     widgets::block::create_block,
     App, AppResult,
 };
@@ -22,6 +23,7 @@ pub struct TopSongs {
     song_list: List,
     info_window: SongInfoWindow,
     area: Rect,
+    artist_id: Option<String>,
 }
 
 impl Default for TopSongs {
@@ -32,6 +34,23 @@ impl Default for TopSongs {
             song_list: List::default(),
             info_window: SongInfoWindow::default(),
             area: Rect::default(),
+            artist_id: None,
+        }
+    }
+}
+
+impl TopSongs {
+    pub fn new(artist_id: String) -> Self {
+        Self {
+            artist_id: Some(artist_id),
+            ..Self::default()
+        }
+    }
+
+    fn get_artist_id(&self) -> Option<String> {
+        match &self.artist_id {
+            Some(artist_id) => Some(artist_id.clone()),
+            None => self.now_playing.artist_ids.first().cloned(),
         }
     }
 }
@@ -57,16 +76,16 @@ impl Component for TopSongs {
     }
 
     fn tick(&mut self, _: &mut App) -> AppResult<Option<Message>> {
-        if !self.now_playing.artist_ids.is_empty() {
-            if self.now_playing.artist_ids[0] != self.artist.id {
-                self.artist.id = self.now_playing.artist_ids[0].clone();
+        if let Some(artist_id) = self.get_artist_id() {
+            if artist_id != self.artist.id {
+                self.artist.id = artist_id;
 
                 return Ok(Some(Message::RefreshArtist));
             }
+        }
 
-            if Some(self.now_playing.song_id.clone()) != self.song_list.current_item_id {
-                self.song_list.current_item_id = Some(self.now_playing.song_id.clone());
-            }
+        if Some(self.now_playing.song_id.clone()) != self.song_list.current_item_id {
+            self.song_list.current_item_id = Some(self.now_playing.song_id.clone());
         }
 
         if self.song_list.get_active_item().1 != self.info_window.song.id {
@@ -79,7 +98,11 @@ impl Component for TopSongs {
             self.song_list.set_items(self.artist.top_songs.clone());
         }
 
-        Ok(Some(Message::RefreshNowPlaying))
+        if let Some(song_ids) = self.song_list.get_prefetch_ids() {
+            return Ok(Some(Message::PrefetchSongs { song_ids }));
+        }
+
+        Ok(None)
     }
 
     fn handle_key_press(&mut self, app: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
@@ -92,6 +115,21 @@ impl Component for TopSongs {
                 offset: self.song_list.active_index,
                 songs: self.song_list.items.clone(),
             })),
+            KeyCode::Char('a') if !self.song_list.items.is_empty() => {
+                Ok(Some(Message::AddToQueue {
+                    song_id: self.song_list.get_active_item().1,
+                }))
+            }
+            KeyCode::Char('f') if !self.info_window.song.is_empty() => {
+                Ok(Some(Message::ToggleSavedSong))
+            }
+            KeyCode::Char('A') if !self.song_list.items.is_empty() => {
+                Ok(Some(Message::ChangeScreen {
+                    new_screen: Box::new(AddToPlaylistScreen::new(
+                        self.song_list.get_active_item().1,
+                    )),
+                }))
+            }
             _ => Ok(None),
         }
     }
@@ -1,6 +1,6 @@
 use ratatui::{
     crossterm::event::KeyEvent,
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
     widgets::{Paragraph, Wrap},
     Frame,
@@ -11,7 +11,8 @@ use crate::{
     core::{
         message::Message,
         spotify::{
-            album::Album, artist::Artist, device::Device, now_playing::NowPlaying, song::Song,
+            album::Album, artist::Artist, device::Device, library::Library,
+            now_playing::NowPlaying, playlist::Playlist, queue::Queue, search::Search, song::Song,
         },
     },
     App, AppResult,
@@ -23,6 +24,7 @@ use super::tab::Tab;
 pub struct TabbedView {
     pub tabs: Vec<Tab>,
     active_tab: usize,
+    area: Option<Rect>,
 }
 
 impl Default for TabbedView {
@@ -30,6 +32,7 @@ impl Default for TabbedView {
         Self {
             tabs: vec![],
             active_tab: 0,
+            area: None,
         }
     }
 }
@@ -39,6 +42,7 @@ impl TabbedView {
         Self {
             tabs,
             active_tab: 0,
+            area: None,
         }
     }
 
@@ -80,7 +84,7 @@ impl Component for TabbedView {
                 Constraint::Max(1),
                 Constraint::Min(1),
             ])
-            .split(frame.area());
+            .split(self.area.unwrap_or(frame.area()));
 
         let horizontal_chunks = Layout::default()
             .direction(Direction::Horizontal)
@@ -121,6 +125,10 @@ impl Component for TabbedView {
         Ok(None)
     }
 
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
     fn get_now_playing(&mut self) -> Option<&mut NowPlaying> {
         self.get_active_component()
             .and_then(|component| component.get_now_playing())
@@ -145,4 +153,31 @@ impl Component for TabbedView {
         self.get_active_component()
             .and_then(|component| component.get_artist())
     }
+
+    fn get_search(&mut self) -> Option<&mut Search> {
+        self.get_active_component()
+            .and_then(|component| component.get_search())
+    }
+
+    fn get_queue(&mut self) -> Option<&mut Queue> {
+        self.get_active_component()
+            .and_then(|component| component.get_queue())
+    }
+
+    fn get_library(&mut self) -> Option<&mut Library> {
+        self.get_active_component()
+            .and_then(|component| component.get_library())
+    }
+
+    fn get_playlist(&mut self) -> Option<&mut Playlist> {
+        self.get_active_component()
+            .and_then(|component| component.get_playlist())
+    }
+
+    fn get_components(&mut self) -> Vec<&mut dyn Component> {
+        self.tabs
+            .iter_mut()
+            .map(|tab| tab.component.as_mut() as &mut dyn Component)
+            .collect()
+    }
 }
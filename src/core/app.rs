@@ -1,5 +1,9 @@
+use std::time::Duration;
+
 use clap::Parser;
+use log::{info, warn};
 use ratatui::crossterm::event::{KeyCode, KeyEvent};
+use tokio::time::interval;
 
 use crate::{
     auth::server::AuthServer,
@@ -10,22 +14,28 @@ use crate::{
 };
 
 use super::{
-    clap::Args,
+    clap::{Args, Command},
     config::Config,
+    event::{AppEvent, KeyReader},
     logging::setup_logging,
     message::{handler::MessageHandler, Message},
-    spotify::client::SpotifyClient,
+    output::{print_usage_error, CliError, OutputFormat, EXIT_SUCCESS},
+    spotify::{client::SpotifyClient, poller::PlaybackPoller},
+    task::TaskManager,
     tui::{init_terminal, install_panic_hook, restore_terminal},
 };
 
 pub type AppResult<T> = color_eyre::Result<T>;
 
+const TICK_RATE: Duration = Duration::from_millis(250);
+
 #[derive(Clone)]
 pub struct App {
     pub is_running: bool,
     pub history: History,
     pub spotify_client: Option<SpotifyClient>,
     pub default_key_press_enabled: bool,
+    pub exit_code: i32,
 }
 
 impl App {
@@ -35,49 +45,125 @@ impl App {
             history: History::default(),
             spotify_client: None,
             default_key_press_enabled: true,
+            exit_code: EXIT_SUCCESS,
         })
     }
 
     pub async fn run(&mut self) -> AppResult<()> {
-        let args = Args::parse();
+        let args = match Args::try_parse() {
+            Ok(args) => args,
+            Err(error) => {
+                if error.use_stderr() && OutputFormat::from_env_args() == OutputFormat::Json {
+                    self.exit_code = print_usage_error(&error);
+
+                    return Ok(());
+                }
+
+                error.exit()
+            }
+        };
 
         install_panic_hook();
         setup_logging()?;
 
+        if let Some(Command::MockServer {
+            host,
+            port,
+            fixtures,
+        }) = &args.command
+        {
+            return Command::run_mock_server(host, *port, fixtures.as_deref(), args.output).await;
+        }
+
         let config = Config::new()?;
         let mut current_screen: Box<dyn Screen> = Box::new(HomeScreen::default());
 
         if config.client_id.is_none() || config.redirect_uri.is_none() || config.scope.is_none() {
+            if let Some(Command::Player { player_command }) = &args.command {
+                let error = CliError::NotConfigured.into();
+
+                self.exit_code = args.output.print_error(player_command.get_name(), &error);
+
+                return Ok(());
+            }
+
             current_screen = Box::new(CreateConfigFormScreen::new(&config));
         } else {
             let result = SpotifyClient::new(config);
 
             match result {
                 Ok(spotify_client) => self.spotify_client = Some(spotify_client),
-                Err(_) => {
+                Err(error) => {
+                    if let Some(Command::Player { player_command }) = &args.command {
+                        let error = CliError::InvalidConfig {
+                            message: error.to_string(),
+                        }
+                        .into();
+
+                        self.exit_code = args.output.print_error(player_command.get_name(), &error);
+
+                        return Ok(());
+                    }
+
                     current_screen = Box::new(ErrorScreen::new("Failed to create Spotify client."))
                 }
             }
 
             if let Some(command) = args.command.clone() {
-                command.handle_command(self, &mut current_screen).await?;
+                let result = command
+                    .handle_command(self, &mut current_screen, args.output)
+                    .await;
+
+                if let Command::Player { player_command } = &command {
+                    if let Err(error) = result {
+                        self.exit_code = args.output.print_error(player_command.get_name(), &error);
+                    }
 
-                if command.is_player_command() {
                     return Ok(());
                 }
+
+                result?;
             }
         }
 
         let mut terminal = init_terminal()?;
         let mut auth_server = AuthServer::default();
+        let mut key_reader = KeyReader::default();
+        let mut task_manager = TaskManager::default();
+        let mut playback_poller: Option<PlaybackPoller> = None;
+        let mut tick_interval = interval(TICK_RATE);
 
         while self.is_running {
             terminal.draw(|frame| current_screen.view(&self, frame))?;
 
-            let mut message_handler =
-                MessageHandler::new(self, &mut current_screen, &mut auth_server, &args);
+            let event = tokio::select! {
+                Some(key) = key_reader.recv() => AppEvent::Key(key),
+                Some(task_event) = task_manager.recv() => AppEvent::Task(Box::new(task_event)),
+                Some(playback_state) = async { playback_poller.as_mut()?.changed().await } => {
+                    AppEvent::Playback(Box::new(playback_state))
+                }
+                _ = tick_interval.tick() => AppEvent::Tick,
+            };
+
+            let mut message_handler = MessageHandler::new(
+                self,
+                &mut current_screen,
+                &mut auth_server,
+                &mut task_manager,
+                &mut playback_poller,
+            );
+
+            message_handler.handle_message(event).await?;
+        }
 
-            message_handler.handle_message().await?;
+        if let Some(spotify_client) = &self.spotify_client {
+            for (endpoint, stats) in spotify_client.get_endpoint_stats() {
+                info!("{}: {:?}", endpoint, stats);
+            }
+
+            if let Err(error) = spotify_client.cache.save() {
+                warn!("Failed to save entity cache: {}", error);
+            }
         }
 
         restore_terminal()?;
@@ -1,25 +1,43 @@
+use std::collections::HashMap;
+
 use clap::{Parser, Subcommand};
 use color_eyre::eyre::eyre;
+use serde_json::json;
 
-use crate::screens::{
-    devices::DevicesScreen,
-    library::LibraryScreen,
-    now_playing::NowPlayingScreen,
-    queue::QueueScreen,
-    search::SearchScreen,
-    view::{album::ViewAlbumScreen, artist::ViewArtistScreen},
-    Screen,
+use crate::{
+    mock::{fixtures::Fixtures, server::MockServer},
+    screens::{
+        devices::DevicesScreen,
+        library::LibraryScreen,
+        now_playing::NowPlayingScreen,
+        queue::QueueScreen,
+        search::SearchScreen,
+        view::{album::ViewAlbumScreen, artist::ViewArtistScreen},
+        Screen,
+    },
+    utils::time::parse_timestamp,
 };
 
 use super::{
     app::{App, AppResult},
-    spotify::{device::Device, player::SpotifyPlayer},
+    output::{to_value, CliError, OutputFormat},
+    spotify::{
+        device::Device,
+        now_playing::NowPlaying,
+        player::{SpotifyPlayer, REPEAT_STATES},
+        song::Song,
+        status::PlayerStatus,
+        uri::SpotifyUri,
+    },
 };
 
 #[derive(Parser, Debug, Clone)]
 #[command(author, version, about)]
 /// Spotify Client TUI - Control Spotify From Your Terminal
 pub struct Args {
+    /// Format for command output, `json` prints one JSON object per command
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub output: OutputFormat,
     #[command(subcommand)]
     pub command: Option<Command>,
 }
@@ -47,24 +65,64 @@ pub enum Command {
     Library,
     /// Change what device Spotify is playing on
     Devices,
+    /// Run a fake Spotify Web API server for offline development, demos and tests
+    MockServer {
+        /// Address the mock server listens on
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+        /// Port the mock server listens on
+        #[arg(long, default_value_t = 8899)]
+        port: u16,
+        /// JSON file with the fixtures served by the mock server (uses the bundled fixtures by default)
+        #[arg(long)]
+        fixtures: Option<String>,
+    },
 }
 
 impl Command {
-    pub fn is_player_command(&self) -> bool {
-        match self {
-            Command::Player { .. } => true,
-            _ => false,
+    pub async fn run_mock_server(
+        host: &str,
+        port: u16,
+        fixtures_path: Option<&str>,
+        output: OutputFormat,
+    ) -> AppResult<()> {
+        let fixtures = Fixtures::load(fixtures_path)?;
+        let server = MockServer::bind(host, port, fixtures).await?;
+        let base_url = format!("http://{}", server.local_addr()?);
+        let env = [
+            ("SPOTIFY_API_URL", format!("{}/v1", base_url)),
+            ("SPOTIFY_ACCOUNTS_URL", base_url.clone()),
+            ("SPOTIFY_WEB_URL", base_url.clone()),
+        ];
+
+        let mut text = format!(
+            "Mock Spotify server listening on {}\n\nPoint the client at it with:",
+            base_url
+        );
+
+        for (name, value) in env.iter() {
+            text = text + &format!("\nexport {}={}", name, value);
         }
+
+        let data = json!({
+            "url": base_url,
+            "env": env.into_iter().collect::<HashMap<&str, String>>(),
+        });
+
+        output.print_success("mock-server", Some(("server", data)), Some(text));
+
+        server.run().await
     }
 
     pub async fn handle_command(
         &self,
         app: &mut App,
         current_screen: &mut Box<dyn Screen>,
+        output: OutputFormat,
     ) -> AppResult<()> {
         match self {
             Command::Player { player_command } => {
-                player_command.handle_command(app).await?;
+                player_command.handle_command(app, output).await?;
             }
             Command::NowPlaying => {
                 app.history.prev.push(current_screen.clone_screen_box());
@@ -96,6 +154,13 @@ impl Command {
                 app.history.prev.push(current_screen.clone());
                 *current_screen = Box::new(DevicesScreen::default());
             }
+            Command::MockServer {
+                host,
+                port,
+                fixtures,
+            } => {
+                Self::run_mock_server(host, *port, fixtures.as_deref(), output).await?;
+            }
         }
 
         Ok(())
@@ -116,40 +181,231 @@ pub enum PlayerCommand {
     Devices,
     /// Play On Device
     SetDevice { id: String },
+    /// Set Volume To A Percentage Or Change It By A Step (e.g. 50, +10, -10)
+    Volume {
+        #[arg(allow_hyphen_values = true, value_parser = parse_volume_change)]
+        volume: VolumeChange,
+    },
+    /// Seek To A Position Or By A Number Of Seconds (e.g. 1:30, +15, -15)
+    Seek {
+        #[arg(allow_hyphen_values = true, value_parser = parse_seek_position)]
+        position: SeekPosition,
+    },
+    /// Set Repeat Mode
+    Repeat {
+        #[arg(value_parser = REPEAT_STATES)]
+        state: String,
+    },
+    /// Play A Spotify URI Or open.spotify.com URL
+    Play {
+        #[arg(value_parser = parse_spotify_uri)]
+        uri: SpotifyUri,
+    },
+    /// Add A Track Or Episode To The Queue
+    Queue {
+        #[arg(value_parser = parse_playable_uri)]
+        uri: SpotifyUri,
+    },
+    /// Save Current Song To Your Library
+    Like,
+    /// Remove Current Song From Your Library
+    Unlike,
+    /// Transfer Playback To A Device By Name
+    Transfer { name: String },
+    /// Print What Is Currently Playing
+    Status {
+        /// Print the status as JSON
+        #[arg(long, conflicts_with = "format")]
+        json: bool,
+        /// Print the status with a template, e.g. '{artist} - {title} [{progress}/{duration}]'.
+        /// Placeholders: {status}, {title}, {artist}, {album}, {id}, {uri}, {progress},
+        /// {duration}, {shuffle}, {repeat}, {volume}, {device}, {saved} ({saved} needs one more
+        /// request)
+        #[arg(long)]
+        format: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum VolumeChange {
+    To(u64),
+    By(i64),
+}
+
+#[derive(Debug, Clone)]
+pub enum SeekPosition {
+    To(u64),
+    By(i64),
+}
+
+fn parse_volume_change(value: &str) -> Result<VolumeChange, String> {
+    let error = format!(
+        "`{}` is not a volume, use a number from 0 to 100 or +n/-n",
+        value
+    );
+
+    match value.starts_with(['+', '-']) {
+        true => value
+            .parse::<i64>()
+            .map(VolumeChange::By)
+            .map_err(|_| error),
+        false => match value.parse::<u64>() {
+            Ok(volume) if volume <= 100 => Ok(VolumeChange::To(volume)),
+            _ => Err(error),
+        },
+    }
+}
+
+fn parse_seek_position(value: &str) -> Result<SeekPosition, String> {
+    let error = format!("`{}` is not a position, use mm:ss or +s/-s", value);
+
+    match value.starts_with(['+', '-']) {
+        true => value
+            .parse::<i64>()
+            .ok()
+            .and_then(|seconds| seconds.checked_mul(1_000))
+            .map(SeekPosition::By)
+            .ok_or(error),
+        false => parse_timestamp(value).map(SeekPosition::To).ok_or(error),
+    }
+}
+
+fn parse_spotify_uri(value: &str) -> Result<SpotifyUri, String> {
+    SpotifyUri::parse(value).map_err(|error| error.to_string())
+}
+
+fn parse_playable_uri(value: &str) -> Result<SpotifyUri, String> {
+    let uri = parse_spotify_uri(value)?;
+
+    match uri.is_playable_item() {
+        true => Ok(uri),
+        false => Err(format!(
+            "`{}` is not a track or episode, only those can be queued",
+            value
+        )),
+    }
 }
 
 impl PlayerCommand {
-    pub async fn handle_command(&self, app: &mut App) -> AppResult<()> {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            PlayerCommand::PausePlay => "pause-play",
+            PlayerCommand::NextSong => "next-song",
+            PlayerCommand::PreviousSong => "previous-song",
+            PlayerCommand::Shuffle => "shuffle",
+            PlayerCommand::Devices => "devices",
+            PlayerCommand::SetDevice { .. } => "set-device",
+            PlayerCommand::Volume { .. } => "volume",
+            PlayerCommand::Seek { .. } => "seek",
+            PlayerCommand::Repeat { .. } => "repeat",
+            PlayerCommand::Play { .. } => "play",
+            PlayerCommand::Queue { .. } => "queue",
+            PlayerCommand::Like => "like",
+            PlayerCommand::Unlike => "unlike",
+            PlayerCommand::Transfer { .. } => "transfer",
+            PlayerCommand::Status { .. } => "status",
+        }
+    }
+
+    pub async fn handle_command(&self, app: &mut App, output: OutputFormat) -> AppResult<()> {
         match app.spotify_client.as_mut() {
-            Some(mut spotify_client) => {
+            Some(spotify_client) => {
+                if !spotify_client.has_credentials() {
+                    return Err(CliError::NotLoggedIn.into());
+                }
+
                 let player = SpotifyPlayer::new();
                 let mut device = Device::default();
-
-                device.refresh(&mut spotify_client).await?;
+                let mut data = None;
+                let mut text = None;
 
                 match self {
                     PlayerCommand::PausePlay => {
-                        player.toggle_pause_play(&mut spotify_client).await?;
+                        player.toggle_pause_play(spotify_client).await?;
                     }
                     PlayerCommand::NextSong => {
-                        player.next_song(&mut spotify_client).await?;
+                        player.next_song(spotify_client).await?;
                     }
                     PlayerCommand::PreviousSong => {
-                        player.previous_song(&mut spotify_client).await?;
+                        player.previous_song(spotify_client).await?;
                     }
                     PlayerCommand::Shuffle => {
-                        player.toggle_shuffle(&mut spotify_client).await?;
+                        player.toggle_shuffle(spotify_client).await?;
                     }
                     PlayerCommand::Devices => {
-                        device.print_devices(&mut spotify_client).await?;
+                        let devices = device.get_devices(spotify_client).await?;
+
+                        text = Some(Device::get_devices_string(&devices));
+                        data = Some(("devices", to_value(&devices)));
                     }
                     PlayerCommand::SetDevice { id } => {
                         device
-                            .set_current_device(&mut spotify_client, id.to_string())
+                            .set_current_device(spotify_client, id.to_string())
                             .await?;
                     }
+                    PlayerCommand::Volume { volume } => match volume {
+                        VolumeChange::To(volume) => {
+                            player.set_volume(spotify_client, *volume).await?;
+                        }
+                        VolumeChange::By(step) => {
+                            player.step_volume(spotify_client, *step).await?;
+                        }
+                    },
+                    PlayerCommand::Seek { position } => match position {
+                        SeekPosition::To(position_ms) => {
+                            player.seek(spotify_client, *position_ms).await?;
+                        }
+                        SeekPosition::By(offset_ms) => {
+                            player.seek_relative(spotify_client, *offset_ms).await?;
+                        }
+                    },
+                    PlayerCommand::Repeat { state } => {
+                        player.set_repeat(spotify_client, state).await?;
+                    }
+                    PlayerCommand::Play { uri } => {
+                        player.play_uri(spotify_client, uri).await?;
+                    }
+                    PlayerCommand::Queue { uri } => {
+                        player.add_uri_to_queue(spotify_client, uri).await?;
+                    }
+                    PlayerCommand::Like | PlayerCommand::Unlike => {
+                        let mut now_playing = NowPlaying::default();
+
+                        now_playing.refresh(spotify_client).await?;
+
+                        if now_playing.song_id.is_empty() {
+                            return Err(CliError::NothingPlaying.into());
+                        }
+
+                        let mut song = Song::new(now_playing.song_id);
+                        let is_saved = matches!(self, PlayerCommand::Like);
+
+                        song.set_saved(spotify_client, is_saved).await?;
+                    }
+                    PlayerCommand::Status { json, format } => {
+                        let include_saved = format
+                            .as_ref()
+                            .is_some_and(|format| format.contains("{saved}"));
+                        let status = PlayerStatus::refresh(spotify_client, include_saved).await?;
+
+                        text = match (json, format) {
+                            (true, _) => Some(serde_json::to_string(&status)?),
+                            (false, Some(format)) => Some(status.format(format)),
+                            (false, None) => Some(status.to_text()),
+                        };
+                        data = Some(("status", to_value(&status)));
+                    }
+                    PlayerCommand::Transfer { name } => {
+                        device.refresh(spotify_client).await?;
+
+                        let id = device.find_device_id(name)?;
+
+                        device.set_current_device(spotify_client, id).await?;
+                    }
                 }
 
+                output.print_success(self.get_name(), data, text);
+
                 Ok(())
             }
             None => Err(eyre!("No `SpotifyClient` set on `App`.")),
@@ -164,3 +420,67 @@ pub enum ViewCommand {
     /// View information on the album currently playing
     Album,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_volume_changes() {
+        assert!(matches!(parse_volume_change("0"), Ok(VolumeChange::To(0))));
+        assert!(matches!(
+            parse_volume_change("100"),
+            Ok(VolumeChange::To(100))
+        ));
+        assert!(matches!(
+            parse_volume_change("+10"),
+            Ok(VolumeChange::By(10))
+        ));
+        assert!(matches!(
+            parse_volume_change("-25"),
+            Ok(VolumeChange::By(-25))
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_volume_changes() {
+        assert!(parse_volume_change("101").is_err());
+        assert!(parse_volume_change("loud").is_err());
+        assert!(parse_volume_change("+").is_err());
+        assert!(parse_volume_change("").is_err());
+    }
+
+    #[test]
+    fn parses_seek_positions() {
+        assert!(matches!(
+            parse_seek_position("1:30"),
+            Ok(SeekPosition::To(90_000))
+        ));
+        assert!(matches!(
+            parse_seek_position("45"),
+            Ok(SeekPosition::To(45_000))
+        ));
+        assert!(matches!(
+            parse_seek_position("+15"),
+            Ok(SeekPosition::By(15_000))
+        ));
+        assert!(matches!(
+            parse_seek_position("-5"),
+            Ok(SeekPosition::By(-5_000))
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_seek_positions() {
+        assert!(parse_seek_position("1:75").is_err());
+        assert!(parse_seek_position("+1:30").is_err());
+        assert!(parse_seek_position("soon").is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_seek_positions() {
+        assert!(parse_seek_position(&format!("+{}", i64::MAX)).is_err());
+        assert!(parse_seek_position(&format!("{}", i64::MIN)).is_err());
+        assert!(parse_seek_position("+99999999999999999999").is_err());
+    }
+}
@@ -1,7 +1,9 @@
 use std::{
+    env,
     fs::{self, File},
     io::Write,
     path::Path,
+    time::Duration,
 };
 
 use serde::{Deserialize, Serialize};
@@ -10,12 +12,42 @@ use crate::utils::directory::get_home_dir;
 
 use super::app::AppResult;
 
-#[derive(Deserialize, Serialize, Clone, Debug)]
+const DEFAULT_API_URL: &str = "https://api.spotify.com/v1";
+const DEFAULT_ACCOUNTS_URL: &str = "https://accounts.spotify.com";
+const DEFAULT_WEB_URL: &str = "https://open.spotify.com";
+const DEFAULT_CACHE_TTL_SECS: u64 = 60 * 60;
+
+const API_URL_ENV_VAR: &str = "SPOTIFY_API_URL";
+const ACCOUNTS_URL_ENV_VAR: &str = "SPOTIFY_ACCOUNTS_URL";
+const WEB_URL_ENV_VAR: &str = "SPOTIFY_WEB_URL";
+
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthFlow {
+    ClientSecret,
+    Pkce,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 pub struct Config {
     pub client_id: Option<String>,
     pub client_secret: Option<String>,
     pub redirect_uri: Option<String>,
     pub scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_flow: Option<AuthFlow>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_non_loopback_callback: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accounts_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub web_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_ttl_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub persist_cache: Option<bool>,
 }
 
 impl Config {
@@ -29,12 +61,7 @@ impl Config {
             return Ok(config);
         }
 
-        Ok(Self {
-            client_id: None,
-            client_secret: None,
-            redirect_uri: None,
-            scope: None,
-        })
+        Ok(Self::default())
     }
 
     pub fn update(&mut self, new_config: Config) -> AppResult<()> {
@@ -42,6 +69,13 @@ impl Config {
         self.client_secret = new_config.client_secret;
         self.redirect_uri = new_config.redirect_uri;
         self.scope = new_config.scope;
+        self.auth_flow = new_config.auth_flow;
+        self.allow_non_loopback_callback = new_config.allow_non_loopback_callback;
+        self.api_url = new_config.api_url;
+        self.accounts_url = new_config.accounts_url;
+        self.web_url = new_config.web_url;
+        self.cache_ttl_secs = new_config.cache_ttl_secs;
+        self.persist_cache = new_config.persist_cache;
 
         let data = serde_json::to_string_pretty(self)?;
         let file_path = Self::get_file_path()?;
@@ -56,6 +90,53 @@ impl Config {
         Ok(())
     }
 
+    pub fn get_auth_flow(&self) -> AuthFlow {
+        if let Some(auth_flow) = self.auth_flow {
+            return auth_flow;
+        }
+
+        match &self.client_secret {
+            Some(client_secret) if !client_secret.trim().is_empty() => AuthFlow::ClientSecret,
+            _ => AuthFlow::Pkce,
+        }
+    }
+
+    pub fn get_api_url(&self) -> String {
+        Self::resolve_url(API_URL_ENV_VAR, &self.api_url, DEFAULT_API_URL)
+    }
+
+    pub fn get_accounts_url(&self) -> String {
+        Self::resolve_url(
+            ACCOUNTS_URL_ENV_VAR,
+            &self.accounts_url,
+            DEFAULT_ACCOUNTS_URL,
+        )
+    }
+
+    pub fn is_default_accounts_url(&self) -> bool {
+        self.get_accounts_url() == DEFAULT_ACCOUNTS_URL
+    }
+
+    pub fn get_web_url(&self) -> String {
+        Self::resolve_url(WEB_URL_ENV_VAR, &self.web_url, DEFAULT_WEB_URL)
+    }
+
+    pub fn get_cache_ttl(&self) -> Duration {
+        Duration::from_secs(self.cache_ttl_secs.unwrap_or(DEFAULT_CACHE_TTL_SECS))
+    }
+
+    fn resolve_url(env_var: &str, configured_url: &Option<String>, default_url: &str) -> String {
+        let url = match env::var(env_var) {
+            Ok(url) if !url.trim().is_empty() => url,
+            _ => match configured_url {
+                Some(url) if !url.trim().is_empty() => url.clone(),
+                _ => default_url.to_string(),
+            },
+        };
+
+        url.trim().trim_end_matches('/').to_string()
+    }
+
     fn get_file_path() -> AppResult<String> {
         let home_dir = get_home_dir()?;
 
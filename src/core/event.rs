@@ -0,0 +1,41 @@
+use std::thread;
+
+use ratatui::crossterm::event::{self, Event, KeyEvent, KeyEventKind};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+use super::{spotify::poller::PlaybackState, task::TaskEvent};
+
+pub enum AppEvent {
+    Tick,
+    Key(KeyEvent),
+    Task(Box<TaskEvent>),
+    Playback(Box<PlaybackState>),
+}
+
+pub struct KeyReader {
+    receiver: UnboundedReceiver<KeyEvent>,
+}
+
+impl Default for KeyReader {
+    fn default() -> Self {
+        let (sender, receiver) = unbounded_channel();
+
+        thread::spawn(move || {
+            while let Ok(event) = event::read() {
+                if let Event::Key(key) = event {
+                    if key.kind == KeyEventKind::Press && sender.send(key).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        Self { receiver }
+    }
+}
+
+impl KeyReader {
+    pub async fn recv(&mut self) -> Option<KeyEvent> {
+        self.receiver.recv().await
+    }
+}
@@ -1,14 +1,34 @@
+use std::{future::Future, iter};
+
+use log::warn;
+
 use crate::{
-    auth::server::AuthServer,
+    auth::server::{AuthCallback, AuthServer},
+    components::Component,
     core::{
         app::{App, AppResult},
-        clap::Args,
-        spotify::{player::SpotifyPlayer, NameAndId},
+        event::AppEvent,
+        spotify::{
+            album::Album,
+            artist::Artist,
+            client::SpotifyClient,
+            player::SpotifyPlayer,
+            playlist::Playlist,
+            poller::{PlaybackPoller, PlaybackState},
+            song::Song,
+            NameAndId,
+        },
+        task::{TaskEvent, TaskKey, TaskManager, TaskOutput},
+    },
+    screens::{
+        error::ErrorScreen, home::HomeScreen, view::playlist::ViewPlaylistScreen, Screen,
+        ScreenType,
     },
-    screens::{home::HomeScreen, Screen, ScreenType},
     utils::error::{
-        handle_error, throw_no_album_error, throw_no_artist_error, throw_no_device_error,
-        throw_no_now_playing_error, throw_no_song_error, throw_no_spotify_client_error,
+        handle_error, handle_error_with_retry, throw_no_album_error, throw_no_artist_error,
+        throw_no_device_error, throw_no_library_error, throw_no_playlist_error,
+        throw_no_queue_error, throw_no_search_error, throw_no_song_error,
+        throw_no_spotify_client_error,
     },
 };
 
@@ -19,7 +39,8 @@ pub struct MessageHandler<'a> {
     pub app: &'a mut App,
     pub current_screen: &'a mut Box<dyn Screen>,
     pub auth_server: &'a mut AuthServer,
-    pub args: &'a Args,
+    pub task_manager: &'a mut TaskManager,
+    pub playback_poller: &'a mut Option<PlaybackPoller>,
 }
 
 impl<'a> MessageHandler<'a> {
@@ -27,52 +48,122 @@ impl<'a> MessageHandler<'a> {
         app: &'a mut App,
         current_screen: &'a mut Box<dyn Screen>,
         auth_server: &'a mut AuthServer,
-        args: &'a Args,
+        task_manager: &'a mut TaskManager,
+        playback_poller: &'a mut Option<PlaybackPoller>,
     ) -> Self {
         Self {
             current_message: None,
             app,
             current_screen,
             auth_server,
-            args,
+            task_manager,
+            playback_poller,
         }
     }
 
-    pub async fn handle_message(&mut self) -> AppResult<()> {
-        self.current_message = self.current_screen.tick(&mut self.app)?;
+    pub async fn handle_message(&mut self, event: AppEvent) -> AppResult<()> {
+        self.start_playback_poller();
+
+        match event {
+            AppEvent::Tick => {
+                self.save_cache();
+
+                self.current_message = self.handle_auth_callback();
+
+                if self.current_message.is_none() {
+                    self.current_message = self.sync_playback_state();
+                }
+
+                if self.current_message.is_none() {
+                    self.current_message = self.current_screen.tick(self.app)?;
+                }
 
-        if self.current_message.is_none() {
-            self.current_message = self.current_screen.handle_event(&mut self.app)?
+                self.handle_current_message().await?;
+            }
+            AppEvent::Key(key) => {
+                self.current_message = self.current_screen.handle_event(self.app, key)?;
+                self.handle_current_message().await?;
+
+                self.current_message = self.current_screen.tick(self.app)?;
+                self.handle_current_message().await?;
+            }
+            AppEvent::Task(task_event) => {
+                self.current_message = self.handle_task_event(*task_event);
+                self.handle_current_message().await?;
+            }
+            AppEvent::Playback(playback_state) => {
+                self.current_message = self.handle_playback_state(*playback_state);
+                self.handle_current_message().await?;
+            }
         }
 
-        while self.current_message.is_some() {
-            self.current_message = match self.current_message.clone().unwrap() {
+        self.cancel_unwanted_tasks();
+
+        Ok(())
+    }
+
+    async fn handle_current_message(&mut self) -> AppResult<()> {
+        while let Some(message) = self.current_message.take() {
+            self.current_message = match message {
                 Message::ChangeScreen { new_screen } => self.change_screen(new_screen).await?,
                 Message::GoToPrevScreen => self.go_to_prev_screen(),
                 Message::GoToNextScreen => self.go_to_next_screen(),
-                Message::SetAuthCode { code } => self.set_auth_code(code).await?,
-                Message::RefreshNowPlaying => self.refresh_now_playing().await,
-                Message::PausePlay => self.pause_play().await,
-                Message::Shuffle => self.shuffle().await,
-                Message::NextSong => self.next_song().await,
-                Message::PrevSong => self.prev_song().await,
-                Message::RefreshDevice => self.refresh_device().await,
-                Message::SetDevice { name, id } => self.set_device(name, id).await,
+                Message::RetryOnPrevScreen { message } => self.retry_on_prev_screen(*message),
+                Message::SetAuthCode { code } => self.set_auth_code(code),
+                Message::Reauthenticate => self.reauthenticate()?,
+                Message::PausePlay => self.pause_play(),
+                Message::Shuffle => self.shuffle(),
+                Message::NextSong => self.next_song(),
+                Message::PrevSong => self.prev_song(),
+                Message::Seek { position_ms } => self.seek(position_ms),
+                Message::SeekRelative { offset_ms } => self.seek_relative(offset_ms),
+                Message::SetVolume { volume_percent } => self.set_volume(volume_percent),
+                Message::StepVolume { step } => self.step_volume(step),
+                Message::CycleRepeat => self.cycle_repeat(),
+                Message::RefreshDevice => self.refresh_device(),
+                Message::SetDevice { name, id } => self.set_device(name, id),
                 Message::PlaySongOnAlbum {
                     track_number,
                     album_id,
-                } => self.play_song_on_album(track_number, album_id).await,
-                Message::PlaySongs { offset, songs } => self.play_songs(offset, songs).await,
-                Message::RefreshSong => self.refresh_song().await,
-                Message::RefreshAlbum => self.refresh_album().await,
-                Message::RefreshArtist => self.refresh_artist().await,
+                } => self.play_song_on_album(track_number, album_id),
+                Message::PlaySongOnPlaylist {
+                    position,
+                    playlist_id,
+                } => self.play_song_on_playlist(position, playlist_id),
+                Message::PlaySongs { offset, songs } => self.play_songs(offset, songs),
+                Message::PlayContext { context_uri } => self.play_context(context_uri),
+                Message::AddToQueue { song_id } => self.add_to_queue(song_id),
+                Message::AddToPlaylist {
+                    playlist_id,
+                    song_id,
+                } => self.add_to_playlist(playlist_id, song_id),
+                Message::CreatePlaylist {
+                    name,
+                    description,
+                    public,
+                } => self.create_playlist(name, description, public),
+                Message::UpdatePlaylistDetails {
+                    name,
+                    description,
+                    public,
+                } => self.update_playlist_details(name, description, public),
+                Message::RemoveFromPlaylist { position } => self.remove_from_playlist(position),
+                Message::MovePlaylistSong { from, to } => self.move_playlist_song(from, to),
+                Message::ToggleSavedSong => self.toggle_saved_song(),
+                Message::ToggleSavedAlbum => self.toggle_saved_album(),
+                Message::RefreshSong => self.refresh_song(),
+                Message::PrefetchSongs { song_ids } => self.prefetch_songs(song_ids),
+                Message::RefreshAlbum => self.refresh_album(),
+                Message::PrefetchAlbums { album_ids } => self.prefetch_albums(album_ids),
+                Message::RefreshArtist => self.refresh_artist(),
+                Message::PrefetchArtists { artist_ids } => self.prefetch_artists(artist_ids),
+                Message::RefreshPlaylist => self.refresh_playlist(),
+                Message::RefreshSearch => self.refresh_search(),
+                Message::NextSearchPage => self.next_search_page(),
+                Message::RefreshQueue => self.refresh_queue(),
+                Message::RefreshLibrary => self.refresh_library(),
+                Message::NextLibraryPage => self.next_library_page(),
             };
-
-            if self.current_message.is_some() {
-                continue;
-            }
-
-            self.current_message = self.current_screen.handle_event(&mut self.app)?
         }
 
         Ok(())
@@ -81,24 +172,17 @@ impl<'a> MessageHandler<'a> {
     async fn change_screen(&mut self, new_screen: Box<dyn Screen>) -> AppResult<Option<Message>> {
         self.app.history.prev.push(self.current_screen.to_owned());
 
+        let mut message = None;
+
         if let Some(spotify_client) = &self.app.spotify_client {
             if new_screen.get_screen_type() == ScreenType::ShowAuthLinkScreen {
-                self.auth_server.start(&spotify_client.config)?;
-            }
-        }
-
-        if let Some(command) = &self.args.command {
-            if new_screen.get_screen_type() == ScreenType::Home && command.is_player_command() {
-                self.app.is_running = false;
-                command
-                    .handle_command(&mut self.app, &mut self.current_screen)
-                    .await?;
+                message = handle_error(self.auth_server.start(spotify_client));
             }
         }
 
         *self.current_screen = new_screen;
 
-        Ok(None)
+        Ok(message)
     }
 
     fn go_to_prev_screen(&mut self) -> Option<Message> {
@@ -116,6 +200,12 @@ impl<'a> MessageHandler<'a> {
         None
     }
 
+    fn retry_on_prev_screen(&mut self, message: Message) -> Option<Message> {
+        self.go_to_prev_screen();
+
+        Some(message)
+    }
+
     fn go_to_next_screen(&mut self) -> Option<Message> {
         if let Some(next_screen) = self.app.history.next.pop() {
             if self.current_screen.get_screen_type() != ScreenType::Exit {
@@ -131,182 +221,805 @@ impl<'a> MessageHandler<'a> {
         None
     }
 
-    async fn set_auth_code(&mut self, code: String) -> AppResult<Option<Message>> {
-        match self.app.spotify_client.as_mut() {
-            Some(spotify_client) => {
-                let result = spotify_client.set_code_and_access_token(code).await;
+    fn handle_auth_callback(&mut self) -> Option<Message> {
+        match self.auth_server.try_recv()? {
+            AuthCallback::Code(code) => Some(Message::SetAuthCode { code }),
+            AuthCallback::Error(error) => {
+                let new_screen = Box::new(ErrorScreen::new(format!(
+                    "Spotify authorization failed: {}",
+                    error
+                )));
 
-                if spotify_client.credentials.is_some() {
-                    self.auth_server.stop()?;
+                Some(Message::ChangeScreen { new_screen })
+            }
+            AuthCallback::StateMismatch => {
+                warn!("Ignored an auth callback that did not match the login in progress");
+
+                None
+            }
+        }
+    }
 
-                    let new_screen = Box::new(HomeScreen::default());
+    fn set_auth_code(&mut self, code: String) -> Option<Message> {
+        let message = Message::SetAuthCode { code: code.clone() };
 
-                    return Ok(Some(Message::ChangeScreen { new_screen }));
-                }
+        self.spawn_action(TaskKey::Auth, message, |mut spotify_client| async move {
+            let result = spotify_client.set_code_and_access_token(code).await;
 
-                Ok(handle_error(result))
+            match spotify_client.has_credentials() {
+                true => Ok(TaskOutput::LoggedIn),
+                false => result.map(|_| TaskOutput::None),
             }
-            None => Ok(throw_no_spotify_client_error()),
+        })
+    }
+
+    fn finish_login(&mut self) -> Option<Message> {
+        if let Err(error) = self.auth_server.stop() {
+            return handle_error::<()>(Err(error));
         }
+
+        let new_screen = Box::new(HomeScreen::default());
+
+        Some(Message::ChangeScreen { new_screen })
     }
 
-    async fn refresh_now_playing(&mut self) -> Option<Message> {
-        match self.app.spotify_client.as_mut() {
-            Some(mut spotify_client) => match self.current_screen.get_now_playing() {
-                Some(now_playing) => {
-                    let result = now_playing.refresh(&mut spotify_client).await;
+    fn reauthenticate(&mut self) -> AppResult<Option<Message>> {
+        if let Some(spotify_client) = self.app.spotify_client.as_mut() {
+            spotify_client.clear_credentials()?;
+        }
+
+        self.app.spotify_client = None;
+        *self.playback_poller = None;
 
-                    handle_error(result)
-                }
-                None => throw_no_now_playing_error(),
+        let new_screen = Box::new(HomeScreen::default());
+
+        Ok(Some(Message::ChangeScreen { new_screen }))
+    }
+
+    fn pause_play(&mut self) -> Option<Message> {
+        self.spawn_player_action(Message::PausePlay, |mut spotify_client| async move {
+            let player = SpotifyPlayer::new();
+
+            player.toggle_pause_play(&mut spotify_client).await?;
+
+            Ok(TaskOutput::None)
+        })
+    }
+
+    fn shuffle(&mut self) -> Option<Message> {
+        self.spawn_player_action(Message::Shuffle, |mut spotify_client| async move {
+            let player = SpotifyPlayer::new();
+
+            player.toggle_shuffle(&mut spotify_client).await?;
+
+            Ok(TaskOutput::None)
+        })
+    }
+
+    fn next_song(&mut self) -> Option<Message> {
+        self.spawn_player_action(Message::NextSong, |mut spotify_client| async move {
+            let player = SpotifyPlayer::new();
+
+            player.next_song(&mut spotify_client).await?;
+
+            Ok(TaskOutput::None)
+        })
+    }
+
+    fn seek(&mut self, position_ms: u64) -> Option<Message> {
+        let message = Message::Seek { position_ms };
+
+        self.spawn_player_action(message, move |mut spotify_client| async move {
+            let player = SpotifyPlayer::new();
+
+            player.seek(&mut spotify_client, position_ms).await?;
+
+            Ok(TaskOutput::None)
+        })
+    }
+
+    fn seek_relative(&mut self, offset_ms: i64) -> Option<Message> {
+        let message = Message::SeekRelative { offset_ms };
+
+        self.spawn_player_action(message, move |mut spotify_client| async move {
+            let player = SpotifyPlayer::new();
+
+            player.seek_relative(&mut spotify_client, offset_ms).await?;
+
+            Ok(TaskOutput::None)
+        })
+    }
+
+    fn set_volume(&mut self, volume_percent: u64) -> Option<Message> {
+        let message = Message::SetVolume { volume_percent };
+
+        self.spawn_player_action(message, move |mut spotify_client| async move {
+            let player = SpotifyPlayer::new();
+
+            player
+                .set_volume(&mut spotify_client, volume_percent)
+                .await?;
+
+            Ok(TaskOutput::None)
+        })
+    }
+
+    fn step_volume(&mut self, step: i64) -> Option<Message> {
+        let message = Message::StepVolume { step };
+
+        self.spawn_player_action(message, move |mut spotify_client| async move {
+            let player = SpotifyPlayer::new();
+
+            player.step_volume(&mut spotify_client, step).await?;
+
+            Ok(TaskOutput::None)
+        })
+    }
+
+    fn cycle_repeat(&mut self) -> Option<Message> {
+        self.spawn_player_action(Message::CycleRepeat, |mut spotify_client| async move {
+            let player = SpotifyPlayer::new();
+
+            player.cycle_repeat(&mut spotify_client).await?;
+
+            Ok(TaskOutput::None)
+        })
+    }
+
+    fn prev_song(&mut self) -> Option<Message> {
+        self.spawn_player_action(Message::PrevSong, |mut spotify_client| async move {
+            let player = SpotifyPlayer::new();
+
+            player.previous_song(&mut spotify_client).await?;
+
+            Ok(TaskOutput::None)
+        })
+    }
+
+    fn refresh_device(&mut self) -> Option<Message> {
+        match self.current_screen.get_device() {
+            Some(device) => {
+                let mut device = device.clone();
+
+                self.spawn_refresh(TaskKey::Device, |mut spotify_client| async move {
+                    device.refresh(&mut spotify_client).await?;
+
+                    Ok(TaskOutput::Device(device))
+                })
+            }
+            None => throw_no_device_error(),
+        }
+    }
+
+    fn set_device(&mut self, name: String, id: String) -> Option<Message> {
+        match self.current_screen.get_device() {
+            Some(device) => {
+                device.current_device_name = Some(name.to_string());
+
+                let mut device = device.clone();
+                let message = Message::SetDevice {
+                    name,
+                    id: id.clone(),
+                };
+
+                self.spawn_player_action(message, |mut spotify_client| async move {
+                    device.set_current_device(&mut spotify_client, id).await?;
+
+                    Ok(TaskOutput::None)
+                })
+            }
+            None => throw_no_device_error(),
+        }
+    }
+
+    fn play_song_on_album(&mut self, track_number: u64, album_id: String) -> Option<Message> {
+        let message = Message::PlaySongOnAlbum {
+            track_number,
+            album_id: album_id.clone(),
+        };
+
+        self.spawn_player_action(message, move |mut spotify_client| async move {
+            let player = SpotifyPlayer::new();
+
+            player
+                .play_song_on_album(&mut spotify_client, track_number, album_id)
+                .await?;
+
+            Ok(TaskOutput::None)
+        })
+    }
+
+    fn play_song_on_playlist(&mut self, position: usize, playlist_id: String) -> Option<Message> {
+        let message = Message::PlaySongOnPlaylist {
+            position,
+            playlist_id: playlist_id.clone(),
+        };
+
+        self.spawn_player_action(message, move |mut spotify_client| async move {
+            let player = SpotifyPlayer::new();
+
+            player
+                .play_song_on_playlist(&mut spotify_client, position, playlist_id)
+                .await?;
+
+            Ok(TaskOutput::None)
+        })
+    }
+
+    fn play_songs(&mut self, offset: usize, songs: Vec<NameAndId>) -> Option<Message> {
+        let message = Message::PlaySongs {
+            offset,
+            songs: songs.clone(),
+        };
+
+        self.spawn_player_action(message, move |mut spotify_client| async move {
+            let player = SpotifyPlayer::new();
+
+            player
+                .play_songs(&mut spotify_client, offset, songs)
+                .await?;
+
+            Ok(TaskOutput::None)
+        })
+    }
+
+    fn play_context(&mut self, context_uri: String) -> Option<Message> {
+        let message = Message::PlayContext {
+            context_uri: context_uri.clone(),
+        };
+
+        self.spawn_player_action(message, |mut spotify_client| async move {
+            let player = SpotifyPlayer::new();
+
+            player
+                .play_context(&mut spotify_client, context_uri)
+                .await?;
+
+            Ok(TaskOutput::None)
+        })
+    }
+
+    fn add_to_queue(&mut self, song_id: String) -> Option<Message> {
+        let message = Message::AddToQueue {
+            song_id: song_id.clone(),
+        };
+
+        self.spawn_player_action(message, |mut spotify_client| async move {
+            let player = SpotifyPlayer::new();
+
+            player.add_to_queue(&mut spotify_client, song_id).await?;
+
+            Ok(TaskOutput::None)
+        })
+    }
+
+    fn add_to_playlist(&mut self, playlist_id: String, song_id: String) -> Option<Message> {
+        let message = Message::AddToPlaylist {
+            playlist_id: playlist_id.clone(),
+            song_id: song_id.clone(),
+        };
+
+        self.spawn_action(
+            TaskKey::PlaylistEdit,
+            message,
+            |mut spotify_client| async move {
+                let uris = vec![format!("spotify:track:{}", song_id)];
+
+                Playlist::new(playlist_id)
+                    .add_songs(&mut spotify_client, uris)
+                    .await?;
+
+                Ok(TaskOutput::AddedToPlaylist)
             },
-            None => throw_no_spotify_client_error(),
+        )
+    }
+
+    fn create_playlist(
+        &mut self,
+        name: String,
+        description: String,
+        public: bool,
+    ) -> Option<Message> {
+        let message = Message::CreatePlaylist {
+            name: name.clone(),
+            description: description.clone(),
+            public,
+        };
+
+        self.spawn_action(
+            TaskKey::PlaylistEdit,
+            message,
+            move |mut spotify_client| async move {
+                let playlist =
+                    Playlist::create(&mut spotify_client, name, description, public).await?;
+
+                Ok(TaskOutput::PlaylistCreated(playlist.id))
+            },
+        )
+    }
+
+    fn update_playlist_details(
+        &mut self,
+        name: String,
+        description: String,
+        public: bool,
+    ) -> Option<Message> {
+        match self.current_screen.get_playlist() {
+            Some(playlist) => {
+                let mut playlist = playlist.clone();
+                let message = Message::UpdatePlaylistDetails {
+                    name: name.clone(),
+                    description: description.clone(),
+                    public,
+                };
+
+                self.spawn_action(
+                    TaskKey::PlaylistEdit,
+                    message,
+                    move |mut spotify_client| async move {
+                        playlist
+                            .update_details(&mut spotify_client, name, description, public)
+                            .await?;
+
+                        Ok(TaskOutput::Playlist(playlist))
+                    },
+                )
+            }
+            None => throw_no_playlist_error(),
         }
     }
 
-    async fn pause_play(&mut self) -> Option<Message> {
-        match self.app.spotify_client.as_mut() {
-            Some(mut spotify_client) => {
-                let player = SpotifyPlayer::new();
-                let result = player.toggle_pause_play(&mut spotify_client).await;
+    fn remove_from_playlist(&mut self, position: usize) -> Option<Message> {
+        match self.current_screen.get_playlist() {
+            Some(playlist) => {
+                let mut playlist = playlist.clone();
+                let message = Message::RemoveFromPlaylist { position };
 
-                handle_error(result)
+                self.spawn_action(
+                    TaskKey::PlaylistEdit,
+                    message,
+                    move |mut spotify_client| async move {
+                        playlist.remove_song(&mut spotify_client, position).await?;
+
+                        Ok(TaskOutput::Playlist(playlist))
+                    },
+                )
             }
-            None => throw_no_spotify_client_error(),
+            None => throw_no_playlist_error(),
         }
     }
 
-    async fn shuffle(&mut self) -> Option<Message> {
-        match self.app.spotify_client.as_mut() {
-            Some(mut spotify_client) => {
-                let player = SpotifyPlayer::new();
-                let result = player.toggle_shuffle(&mut spotify_client).await;
+    fn move_playlist_song(&mut self, from: usize, to: usize) -> Option<Message> {
+        match self.current_screen.get_playlist() {
+            Some(playlist) => {
+                let mut playlist = playlist.clone();
+                let message = Message::MovePlaylistSong { from, to };
 
-                handle_error(result)
+                self.spawn_action(
+                    TaskKey::PlaylistEdit,
+                    message,
+                    move |mut spotify_client| async move {
+                        playlist.move_song(&mut spotify_client, from, to).await?;
+
+                        Ok(TaskOutput::Playlist(playlist))
+                    },
+                )
             }
-            None => throw_no_spotify_client_error(),
+            None => throw_no_playlist_error(),
         }
     }
 
-    async fn next_song(&mut self) -> Option<Message> {
-        match self.app.spotify_client.as_mut() {
-            Some(mut spotify_client) => {
-                let player = SpotifyPlayer::new();
-                let result = player.next_song(&mut spotify_client).await;
+    fn toggle_saved_song(&mut self) -> Option<Message> {
+        match self.current_screen.get_song() {
+            Some(song) => {
+                let mut song = song.clone();
 
-                handle_error(result)
+                self.spawn_action(
+                    TaskKey::SavedSong,
+                    Message::ToggleSavedSong,
+                    |mut spotify_client| async move {
+                        song.toggle_saved(&mut spotify_client).await?;
+
+                        Ok(TaskOutput::SongSaved(song.is_saved))
+                    },
+                )
             }
-            None => throw_no_spotify_client_error(),
+            None => throw_no_song_error(),
         }
     }
 
-    async fn prev_song(&mut self) -> Option<Message> {
-        match self.app.spotify_client.as_mut() {
-            Some(mut spotify_client) => {
-                let player = SpotifyPlayer::new();
-                let result = player.previous_song(&mut spotify_client).await;
+    fn toggle_saved_album(&mut self) -> Option<Message> {
+        match self.current_screen.get_album() {
+            Some(album) => {
+                let mut album = album.clone();
 
-                handle_error(result)
+                self.spawn_action(
+                    TaskKey::SavedAlbum,
+                    Message::ToggleSavedAlbum,
+                    |mut spotify_client| async move {
+                        album.toggle_saved(&mut spotify_client).await?;
+
+                        Ok(TaskOutput::AlbumSaved(album.is_saved))
+                    },
+                )
             }
-            None => throw_no_spotify_client_error(),
+            None => throw_no_album_error(),
         }
     }
 
-    async fn refresh_device(&mut self) -> Option<Message> {
-        match self.app.spotify_client.as_mut() {
-            Some(mut spotify_client) => match self.current_screen.get_device() {
-                Some(device) => {
-                    let result = device.refresh(&mut spotify_client).await;
+    fn refresh_song(&mut self) -> Option<Message> {
+        match self.current_screen.get_song() {
+            Some(song) => {
+                let mut song = song.clone();
 
-                    handle_error(result)
-                }
-                None => throw_no_device_error(),
-            },
-            None => throw_no_spotify_client_error(),
+                self.spawn_refresh(TaskKey::Song, |mut spotify_client| async move {
+                    song.refresh(&mut spotify_client).await?;
+
+                    Ok(TaskOutput::Song(song))
+                })
+            }
+            None => throw_no_song_error(),
         }
     }
 
-    async fn set_device(&mut self, name: String, id: String) -> Option<Message> {
-        match self.app.spotify_client.as_mut() {
-            Some(mut spotify_client) => match self.current_screen.get_device() {
-                Some(device) => {
-                    let result = device.set_current_device(&mut spotify_client, id).await;
+    fn prefetch_songs(&mut self, song_ids: Vec<String>) -> Option<Message> {
+        let target = format!("tracks:{}", song_ids.join(","));
+
+        self.spawn_prefetch(target, |mut spotify_client| async move {
+            Song::prefetch(&mut spotify_client, &song_ids).await
+        })
+    }
+
+    fn refresh_album(&mut self) -> Option<Message> {
+        match self.current_screen.get_album() {
+            Some(album) => {
+                let mut album = album.clone();
 
-                    device.current_device_name = Some(name.to_string());
+                self.spawn_refresh(TaskKey::Album, |mut spotify_client| async move {
+                    album.refresh(&mut spotify_client).await?;
 
-                    handle_error(result)
-                }
-                None => throw_no_device_error(),
-            },
-            None => throw_no_spotify_client_error(),
+                    Ok(TaskOutput::Album(album))
+                })
+            }
+            None => throw_no_album_error(),
         }
     }
 
-    async fn play_song_on_album(&mut self, track_number: u64, album_id: String) -> Option<Message> {
-        match self.app.spotify_client.as_mut() {
-            Some(mut spotify_client) => {
-                let player = SpotifyPlayer::new();
-                let result = player
-                    .play_song_on_album(&mut spotify_client, track_number, album_id)
-                    .await;
+    fn prefetch_albums(&mut self, album_ids: Vec<String>) -> Option<Message> {
+        let target = format!("albums:{}", album_ids.join(","));
+
+        self.spawn_prefetch(target, |mut spotify_client| async move {
+            Album::prefetch(&mut spotify_client, &album_ids).await
+        })
+    }
+
+    fn refresh_playlist(&mut self) -> Option<Message> {
+        match self.current_screen.get_playlist() {
+            Some(playlist) => {
+                let mut playlist = playlist.clone();
 
-                handle_error(result)
+                self.spawn_refresh(TaskKey::Playlist, |mut spotify_client| async move {
+                    playlist.refresh(&mut spotify_client).await?;
+
+                    Ok(TaskOutput::Playlist(playlist))
+                })
             }
-            None => throw_no_spotify_client_error(),
+            None => throw_no_playlist_error(),
         }
     }
 
-    async fn play_songs(&mut self, offset: usize, songs: Vec<NameAndId>) -> Option<Message> {
-        match self.app.spotify_client.as_mut() {
-            Some(mut spotify_client) => {
-                let player = SpotifyPlayer::new();
-                let result = player.play_songs(&mut spotify_client, offset, songs).await;
+    fn refresh_artist(&mut self) -> Option<Message> {
+        match self.current_screen.get_artist() {
+            Some(artist) => {
+                let mut artist = artist.clone();
 
-                handle_error(result)
+                self.spawn_refresh(TaskKey::Artist, |mut spotify_client| async move {
+                    artist.refresh(&mut spotify_client).await?;
+
+                    Ok(TaskOutput::Artist(artist))
+                })
             }
-            None => throw_no_spotify_client_error(),
+            None => throw_no_artist_error(),
+        }
+    }
+
+    fn prefetch_artists(&mut self, artist_ids: Vec<String>) -> Option<Message> {
+        let target = format!("artists:{}", artist_ids.join(","));
+
+        self.spawn_prefetch(target, |mut spotify_client| async move {
+            Artist::prefetch(&mut spotify_client, &artist_ids).await
+        })
+    }
+
+    fn refresh_search(&mut self) -> Option<Message> {
+        match self.current_screen.get_search() {
+            Some(search) => {
+                let mut search = search.clone();
+
+                self.spawn_refresh(TaskKey::Search, |mut spotify_client| async move {
+                    search.refresh(&mut spotify_client).await?;
+
+                    Ok(TaskOutput::Search(search))
+                })
+            }
+            None => throw_no_search_error(),
+        }
+    }
+
+    fn next_search_page(&mut self) -> Option<Message> {
+        match self.current_screen.get_search() {
+            Some(search) => {
+                let mut search = search.clone();
+
+                self.spawn_refresh(TaskKey::SearchPage, |mut spotify_client| async move {
+                    search.next_page(&mut spotify_client).await?;
+
+                    Ok(TaskOutput::Search(search))
+                })
+            }
+            None => throw_no_search_error(),
+        }
+    }
+
+    fn refresh_queue(&mut self) -> Option<Message> {
+        match self.current_screen.get_queue() {
+            Some(queue) => {
+                let mut queue = queue.clone();
+
+                self.spawn_refresh(TaskKey::Queue, |mut spotify_client| async move {
+                    queue.refresh(&mut spotify_client).await?;
+
+                    Ok(TaskOutput::Queue(queue))
+                })
+            }
+            None => throw_no_queue_error(),
         }
     }
 
-    async fn refresh_song(&mut self) -> Option<Message> {
-        match self.app.spotify_client.as_mut() {
-            Some(mut spotify_client) => match self.current_screen.get_song() {
-                Some(song) => {
-                    let result = song.refresh(&mut spotify_client).await;
+    fn refresh_library(&mut self) -> Option<Message> {
+        match self.current_screen.get_library() {
+            Some(library) => {
+                let mut library = library.clone();
+
+                self.spawn_refresh(TaskKey::Library, |mut spotify_client| async move {
+                    library.refresh(&mut spotify_client).await?;
+
+                    Ok(TaskOutput::Library(library))
+                })
+            }
+            None => throw_no_library_error(),
+        }
+    }
+
+    fn next_library_page(&mut self) -> Option<Message> {
+        match self.current_screen.get_library() {
+            Some(library) => {
+                let mut library = library.clone();
+
+                self.spawn_refresh(TaskKey::LibraryPage, |mut spotify_client| async move {
+                    library.next_page(&mut spotify_client).await?;
+
+                    Ok(TaskOutput::Library(library))
+                })
+            }
+            None => throw_no_library_error(),
+        }
+    }
+
+    fn spawn_refresh<F, T>(&mut self, key: TaskKey, task: F) -> Option<Message>
+    where
+        F: FnOnce(SpotifyClient) -> T,
+        T: Future<Output = AppResult<TaskOutput>> + Send + 'static,
+    {
+        let target = key
+            .get_target(self.current_screen.as_mut())
+            .unwrap_or_default();
+
+        if self.task_manager.is_running_for(key, &target) {
+            return None;
+        }
+
+        self.spawn_task(key, target, task)
+    }
+
+    fn spawn_prefetch<F, T>(&mut self, target: String, task: F) -> Option<Message>
+    where
+        F: FnOnce(SpotifyClient) -> T,
+        T: Future<Output = AppResult<()>> + Send + 'static,
+    {
+        let is_cache_enabled = match &self.app.spotify_client {
+            Some(spotify_client) => spotify_client.cache.is_enabled(),
+            None => false,
+        };
 
-                    handle_error(result)
+        if !is_cache_enabled || self.task_manager.is_running_for(TaskKey::Prefetch, &target) {
+            return None;
+        }
+
+        self.spawn_task(TaskKey::Prefetch, target, |spotify_client| {
+            let prefetch = task(spotify_client);
+
+            async move {
+                if let Err(error) = prefetch.await {
+                    warn!("Failed to prefetch entities: {}", error);
                 }
-                None => throw_no_song_error(),
-            },
+
+                Ok(TaskOutput::None)
+            }
+        })
+    }
+
+    fn spawn_action<F, T>(&mut self, key: TaskKey, message: Message, task: F) -> Option<Message>
+    where
+        F: FnOnce(SpotifyClient) -> T,
+        T: Future<Output = AppResult<TaskOutput>> + Send + 'static,
+    {
+        if self.task_manager.is_running(key) {
+            self.task_manager.defer(key, message);
+
+            return None;
+        }
+
+        let target = key
+            .get_target(self.current_screen.as_mut())
+            .unwrap_or_default();
+
+        self.spawn_task(key, target, task)
+    }
+
+    fn spawn_player_action<F, T>(&mut self, message: Message, task: F) -> Option<Message>
+    where
+        F: FnOnce(SpotifyClient) -> T,
+        T: Future<Output = AppResult<TaskOutput>> + Send + 'static,
+    {
+        self.spawn_action(TaskKey::Player, message, task)
+    }
+
+    fn spawn_task<F, T>(&mut self, key: TaskKey, target: String, task: F) -> Option<Message>
+    where
+        F: FnOnce(SpotifyClient) -> T,
+        T: Future<Output = AppResult<TaskOutput>> + Send + 'static,
+    {
+        match &self.app.spotify_client {
+            Some(spotify_client) => {
+                self.task_manager
+                    .spawn(key, target, task(spotify_client.clone()));
+
+                None
+            }
             None => throw_no_spotify_client_error(),
         }
     }
 
-    async fn refresh_album(&mut self) -> Option<Message> {
-        match self.app.spotify_client.as_mut() {
-            Some(mut spotify_client) => match self.current_screen.get_album() {
-                Some(album) => {
-                    let result = album.refresh(&mut spotify_client).await;
+    fn handle_task_event(&mut self, event: TaskEvent) -> Option<Message> {
+        if !self.task_manager.finish(&event) {
+            return None;
+        }
 
-                    handle_error(result)
+        match event.result {
+            Ok(output) => {
+                self.apply_task_output(event.key, &event.target, &output);
+
+                if let (TaskKey::Player, Some(playback_poller)) = (event.key, &self.playback_poller)
+                {
+                    playback_poller.refresh_now();
                 }
-                None => throw_no_album_error(),
-            },
-            None => throw_no_spotify_client_error(),
+
+                let message = match output {
+                    TaskOutput::LoggedIn => self.finish_login(),
+                    TaskOutput::PlaylistCreated(playlist_id) => Some(Message::ChangeScreen {
+                        new_screen: Box::new(ViewPlaylistScreen::new(playlist_id)),
+                    }),
+                    TaskOutput::AddedToPlaylist
+                        if self.current_screen.get_screen_type()
+                            == ScreenType::AddToPlaylistScreen =>
+                    {
+                        Some(Message::GoToPrevScreen)
+                    }
+                    _ => None,
+                };
+
+                message.or_else(|| self.task_manager.take_deferred(event.key))
+            }
+            Err(error) => {
+                self.task_manager.clear_deferred(event.key);
+
+                handle_error_with_retry::<()>(Err(error), event.key.get_retry_message())
+            }
         }
     }
 
-    async fn refresh_artist(&mut self) -> Option<Message> {
-        match self.app.spotify_client.as_mut() {
-            Some(mut spotify_client) => match self.current_screen.get_artist() {
-                Some(artist) => {
-                    let result = artist.refresh(&mut spotify_client).await;
+    fn start_playback_poller(&mut self) {
+        if self.playback_poller.is_some() {
+            return;
+        }
+
+        if let Some(spotify_client) = &self.app.spotify_client {
+            if spotify_client.has_credentials() {
+                *self.playback_poller = Some(PlaybackPoller::new(spotify_client.clone()));
 
-                    handle_error(result)
+                self.spawn_prefetch("cache".to_string(), |mut spotify_client| async move {
+                    spotify_client.load_cache().await
+                });
+            }
+        }
+    }
+
+    fn sync_playback_state(&mut self) -> Option<Message> {
+        let playback_state = self.playback_poller.as_ref()?.get_state();
+
+        self.for_each_component(|component| {
+            if let Some(now_playing) = component.get_now_playing() {
+                if now_playing.is_empty() {
+                    *now_playing = playback_state.now_playing.clone();
                 }
-                None => throw_no_artist_error(),
-            },
-            None => throw_no_spotify_client_error(),
+            }
+        });
+
+        self.handle_playback_error()
+    }
+
+    fn handle_playback_state(&mut self, playback_state: PlaybackState) -> Option<Message> {
+        self.for_each_component(|component| {
+            if let Some(now_playing) = component.get_now_playing() {
+                now_playing.sync(&playback_state.now_playing);
+            }
+        });
+
+        self.handle_playback_error()
+    }
+
+    fn handle_playback_error(&mut self) -> Option<Message> {
+        if self.current_screen.get_screen_type() != ScreenType::NowPlayingScreen {
+            return None;
+        }
+
+        let error = self.playback_poller.as_mut()?.take_error()?;
+
+        handle_error::<()>(Err(error.into()))
+    }
+
+    fn save_cache(&self) {
+        if let Some(spotify_client) = &self.app.spotify_client {
+            spotify_client.cache.save_if_due();
+        }
+    }
+
+    fn apply_task_output(&mut self, key: TaskKey, target: &str, output: &TaskOutput) {
+        self.for_each_component(|component| {
+            if key.get_target(component).as_deref() == Some(target) {
+                output.apply(component);
+            }
+        });
+    }
+
+    fn cancel_unwanted_tasks(&mut self) {
+        let mut wanted = vec![];
+
+        self.for_each_component(|component| {
+            for key in TaskKey::REFRESH_KEYS {
+                if let Some(target) = key.get_target(component) {
+                    wanted.push((key, target));
+                }
+            }
+        });
+
+        self.task_manager.cancel_unwanted(&wanted);
+    }
+
+    fn for_each_component(&mut self, mut callback: impl FnMut(&mut dyn Component)) {
+        let history = &mut self.app.history;
+        let screens = iter::once(&mut *self.current_screen)
+            .chain(history.prev.iter_mut())
+            .chain(history.next.iter_mut());
+
+        for screen in screens {
+            let components = screen.get_components();
+
+            match components.is_empty() {
+                true => callback(screen.as_mut()),
+                false => components.into_iter().for_each(&mut callback),
+            }
         }
     }
 }
@@ -8,14 +8,30 @@ pub enum Message {
     },
     GoToPrevScreen,
     GoToNextScreen,
-    RefreshNowPlaying,
+    RetryOnPrevScreen {
+        message: Box<Message>,
+    },
     SetAuthCode {
         code: String,
     },
+    Reauthenticate,
     PausePlay,
     Shuffle,
     NextSong,
     PrevSong,
+    Seek {
+        position_ms: u64,
+    },
+    SeekRelative {
+        offset_ms: i64,
+    },
+    SetVolume {
+        volume_percent: u64,
+    },
+    StepVolume {
+        step: i64,
+    },
+    CycleRepeat,
     RefreshDevice,
     SetDevice {
         name: String,
@@ -25,11 +41,59 @@ pub enum Message {
         track_number: u64,
         album_id: String,
     },
+    PlaySongOnPlaylist {
+        position: usize,
+        playlist_id: String,
+    },
     PlaySongs {
         offset: usize,
         songs: Vec<NameAndId>,
     },
+    PlayContext {
+        context_uri: String,
+    },
+    AddToQueue {
+        song_id: String,
+    },
+    AddToPlaylist {
+        playlist_id: String,
+        song_id: String,
+    },
+    CreatePlaylist {
+        name: String,
+        description: String,
+        public: bool,
+    },
+    UpdatePlaylistDetails {
+        name: String,
+        description: String,
+        public: bool,
+    },
+    RemoveFromPlaylist {
+        position: usize,
+    },
+    MovePlaylistSong {
+        from: usize,
+        to: usize,
+    },
+    ToggleSavedSong,
+    ToggleSavedAlbum,
     RefreshSong,
+    PrefetchSongs {
+        song_ids: Vec<String>,
+    },
     RefreshAlbum,
+    PrefetchAlbums {
+        album_ids: Vec<String>,
+    },
     RefreshArtist,
+    PrefetchArtists {
+        artist_ids: Vec<String>,
+    },
+    RefreshPlaylist,
+    RefreshSearch,
+    NextSearchPage,
+    RefreshQueue,
+    RefreshLibrary,
+    NextLibraryPage,
 }
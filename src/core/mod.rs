@@ -1,7 +1,10 @@
 pub mod app;
 pub mod clap;
 pub mod config;
+pub mod event;
 pub mod logging;
 pub mod message;
+pub mod output;
 pub mod spotify;
+pub mod task;
 pub mod tui;
@@ -0,0 +1,168 @@
+use std::{
+    env,
+    fmt::{self, Display},
+};
+
+use clap::ValueEnum;
+use color_eyre::Report;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::spotify::error::SpotifyError;
+
+pub const EXIT_SUCCESS: i32 = 0;
+pub const EXIT_ERROR: i32 = 1;
+pub const EXIT_USAGE: i32 = 2;
+pub const EXIT_AUTH: i32 = 3;
+pub const EXIT_NO_PLAYBACK: i32 = 4;
+pub const EXIT_NOT_FOUND: i32 = 5;
+pub const EXIT_PREMIUM_REQUIRED: i32 = 6;
+pub const EXIT_RATE_LIMITED: i32 = 7;
+pub const EXIT_NETWORK: i32 = 8;
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    pub fn from_env_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        for (index, arg) in args.iter().enumerate() {
+            let value = match arg.strip_prefix("--output=") {
+                Some(value) => Some(value),
+                None if arg == "--output" => args.get(index + 1).map(|value| value.as_str()),
+                None => None,
+            };
+
+            if value == Some("json") {
+                return OutputFormat::Json;
+            }
+        }
+
+        OutputFormat::Text
+    }
+
+    pub fn print_success(&self, command: &str, data: Option<(&str, Value)>, text: Option<String>) {
+        match self {
+            OutputFormat::Json => {
+                let mut output = json!({
+                    "ok": true,
+                    "command": command,
+                });
+
+                if let Some((key, value)) = data {
+                    output[key] = value;
+                }
+
+                println!("{}", output);
+            }
+            OutputFormat::Text => {
+                if let Some(text) = text {
+                    println!("{}", text);
+                }
+            }
+        }
+    }
+
+    pub fn print_error(&self, command: &str, error: &Report) -> i32 {
+        match self {
+            OutputFormat::Json => print_json_error(Value::from(command), error),
+            OutputFormat::Text => {
+                eprintln!("Error: {}", error);
+
+                get_error_kind(error).1
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    InvalidArgument { message: String },
+    InvalidConfig { message: String },
+    NotConfigured,
+    NotLoggedIn,
+    NothingPlaying,
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::InvalidArgument { message } => write!(f, "{}", message),
+            CliError::InvalidConfig { message } => write!(f, "{}", message),
+            CliError::NotConfigured => write!(
+                f,
+                "No config found. Run spotify-client-tui without a command to set one up."
+            ),
+            CliError::NotLoggedIn => write!(
+                f,
+                "Not logged in to Spotify. Run spotify-client-tui without a command to log in."
+            ),
+            CliError::NothingPlaying => write!(f, "No song is currently playing."),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+pub fn get_error_kind(error: &Report) -> (&'static str, i32) {
+    if let Some(cli_error) = error.downcast_ref::<CliError>() {
+        return match cli_error {
+            CliError::InvalidArgument { .. } => ("invalid_argument", EXIT_USAGE),
+            CliError::InvalidConfig { .. } => ("invalid_config", EXIT_ERROR),
+            CliError::NotConfigured => ("not_configured", EXIT_AUTH),
+            CliError::NotLoggedIn => ("not_logged_in", EXIT_AUTH),
+            CliError::NothingPlaying => ("nothing_playing", EXIT_NO_PLAYBACK),
+        };
+    }
+
+    match error.downcast_ref::<SpotifyError>() {
+        Some(SpotifyError::NoActiveDevice) => ("no_active_device", EXIT_NO_PLAYBACK),
+        Some(SpotifyError::PremiumRequired) => ("premium_required", EXIT_PREMIUM_REQUIRED),
+        Some(SpotifyError::MissingScope { .. }) => ("missing_scope", EXIT_AUTH),
+        Some(SpotifyError::RateLimited { .. }) => ("rate_limited", EXIT_RATE_LIMITED),
+        Some(SpotifyError::Network { .. }) => ("network", EXIT_NETWORK),
+        Some(SpotifyError::NotFound { .. }) => ("not_found", EXIT_NOT_FOUND),
+        Some(SpotifyError::AuthRevoked) => ("auth_revoked", EXIT_AUTH),
+        Some(SpotifyError::Api { .. }) => ("api", EXIT_ERROR),
+        Some(SpotifyError::InvalidResponse { .. }) => ("invalid_response", EXIT_ERROR),
+        None => ("error", EXIT_ERROR),
+    }
+}
+
+pub fn print_usage_error(error: &clap::Error) -> i32 {
+    let message = error.to_string();
+    let message = message
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches("error: ")
+        .to_string();
+
+    print_json_error(Value::Null, &CliError::InvalidArgument { message }.into())
+}
+
+fn print_json_error(command: Value, error: &Report) -> i32 {
+    let (kind, exit_code) = get_error_kind(error);
+
+    let output = json!({
+        "ok": false,
+        "command": command,
+        "error": {
+            "kind": kind,
+            "message": error.to_string(),
+            "exit_code": exit_code,
+        },
+    });
+
+    println!("{}", output);
+
+    exit_code
+}
+
+pub fn to_value<T: Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap_or_default()
+}
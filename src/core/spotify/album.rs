@@ -1,28 +1,82 @@
-use serde_json::Value;
+use log::warn;
 
-use crate::{core::app::AppResult, utils::value::GetOrDefault};
+use crate::core::app::AppResult;
 
-use super::{client::SpotifyClient, NameAndId};
+use super::{
+    client::SpotifyClient,
+    models::{album::AlbumObject, common::ImageObject},
+    pager::Pager,
+    NameAndId,
+};
+
+const MAX_BATCH_IDS: usize = 20;
+const SAVED_ROUTE: &str = "me/albums/contains";
 
 #[derive(Debug, Clone)]
 pub struct Album {
     pub id: String,
+    pub uri: String,
     pub name: String,
+    pub album_type: String,
     pub artist_names: Vec<String>,
+    pub artist_ids: Vec<String>,
     pub year: String,
+    pub images: Vec<ImageObject>,
     pub songs: Vec<NameAndId>,
     pub total_songs: u64,
+    pub popularity: u64,
+    pub is_saved: Option<bool>,
 }
 
 impl Default for Album {
     fn default() -> Self {
         Self {
             id: String::new(),
+            uri: String::new(),
             name: String::new(),
+            album_type: String::new(),
             artist_names: vec![],
+            artist_ids: vec![],
             year: String::new(),
+            images: vec![],
             songs: vec![],
             total_songs: 0,
+            popularity: 0,
+            is_saved: None,
+        }
+    }
+}
+
+impl From<AlbumObject> for Album {
+    fn from(album: AlbumObject) -> Self {
+        Self {
+            id: album.album.id.unwrap_or_default(),
+            uri: album.album.uri.unwrap_or_default(),
+            name: album.album.name,
+            album_type: album.album.album_type.unwrap_or_default(),
+            artist_names: album
+                .album
+                .artists
+                .iter()
+                .map(|artist| artist.name.clone())
+                .collect(),
+            artist_ids: album
+                .album
+                .artists
+                .iter()
+                .filter_map(|artist| artist.id.clone())
+                .collect(),
+            year: album.album.release_date.unwrap_or_default(),
+            images: album.album.images,
+            songs: album
+                .tracks
+                .items
+                .into_iter()
+                .map(|track| (track.name, track.id.unwrap_or_default()))
+                .collect(),
+            total_songs: album.album.total_tracks,
+            popularity: album.popularity,
+            is_saved: None,
         }
     }
 }
@@ -31,53 +85,74 @@ impl Album {
     pub fn new(id: String) -> Self {
         Self {
             id,
-            name: String::new(),
-            artist_names: vec![],
-            year: String::new(),
-            songs: vec![],
-            total_songs: 0,
+            ..Self::default()
         }
     }
 
+    pub async fn prefetch(spotify_client: &mut SpotifyClient, ids: &[String]) -> AppResult<()> {
+        spotify_client
+            .get_cached_batch::<AlbumObject>("albums", ids, MAX_BATCH_IDS)
+            .await?;
+        spotify_client
+            .get_cached_flags(SAVED_ROUTE, ids, MAX_BATCH_IDS)
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn refresh(&mut self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
         let url = format!("albums/{}", self.id);
-        let response = spotify_client.get(&url).await?;
-        let json = response.json::<Value>().await?;
-
-        let name = json.get_string_or_default("name");
-        let mut artists = Vec::<String>::new();
-        let year = json.get_string_or_default("release_date");
-        let mut songs = Vec::<NameAndId>::new();
-        let total_songs = json.get_number_or_default("total_tracks");
+        let mut album = spotify_client.get_cached_json::<AlbumObject>(&url).await?;
 
-        let artists_array = json.get_array_or_default("artists");
+        album.tracks.items = Pager::from_page(album.tracks.clone())
+            .with_cache()
+            .collect_all(spotify_client)
+            .await?;
 
-        for artist_value in artists_array {
-            let artist = artist_value.get_string_or_default("name");
+        *self = Album::from(album);
 
-            artists.push(artist);
+        if let Err(error) = self.refresh_saved(spotify_client).await {
+            warn!("Could not check whether the album is saved: {}", error);
+            self.is_saved = None;
         }
 
-        if let Some(tracks) = json.get("tracks") {
-            let songs_array = tracks.get_array_or_default("items");
+        Ok(())
+    }
 
-            for song_value in songs_array {
-                let song_name = song_value.get_string_or_default("name");
-                let song_id = song_value.get_string_or_default("id");
+    pub async fn refresh_saved(&mut self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
+        let ids = [self.id.clone()];
+        let saved = spotify_client
+            .get_cached_flags(SAVED_ROUTE, &ids, MAX_BATCH_IDS)
+            .await?;
 
-                songs.push((song_name, song_id))
-            }
-        }
+        self.is_saved = saved.get(&self.id).copied();
+
+        Ok(())
+    }
+
+    pub async fn toggle_saved(&mut self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
+        let url = format!("me/albums?ids={}", self.id);
+        let is_saved = !self.is_saved.unwrap_or_default();
+
+        match is_saved {
+            true => spotify_client.put(&url, None).await?,
+            false => spotify_client.delete(&url, None).await?,
+        };
 
-        self.name = name;
-        self.artist_names = artists;
-        self.year = year;
-        self.songs = songs;
-        self.total_songs = total_songs;
+        spotify_client.set_cached_flag(SAVED_ROUTE, &self.id, is_saved);
+        self.is_saved = Some(is_saved);
 
         Ok(())
     }
 
+    pub fn get_saved_marker(&self) -> &str {
+        match self.is_saved {
+            Some(true) => " ♥",
+            Some(false) => " ♡",
+            None => "",
+        }
+    }
+
     pub fn get_first_artist(&self) -> String {
         let mut first_artist = String::new();
 
@@ -1,20 +1,28 @@
+use crate::{utils::string::Capitalize, AppResult};
 use color_eyre::eyre::Error;
 use num_format::{Locale, ToFormattedString};
-use reqwest::get;
 use scraper::{Html, Selector};
-use serde_json::Value;
 
-use crate::{
-    utils::{string::Capitalize, value::GetOrDefault},
-    AppResult,
+use super::{
+    client::SpotifyClient,
+    models::{
+        album::SimplifiedAlbumObject,
+        artist::{ArtistObject, TopTracksResponse},
+        common::ImageObject,
+    },
+    pager::Pager,
+    NameAndId,
 };
 
-use super::{client::SpotifyClient, NameAndId};
+const MAX_BATCH_IDS: usize = 50;
 
 #[derive(Clone, Debug)]
 pub struct Artist {
     pub id: String,
+    pub uri: String,
     pub name: String,
+    pub images: Vec<ImageObject>,
+    pub popularity: u64,
     pub top_songs: Vec<NameAndId>,
     pub albums: Vec<NameAndId>,
     pub singles: Vec<NameAndId>,
@@ -27,7 +35,10 @@ impl Default for Artist {
     fn default() -> Self {
         Self {
             id: String::new(),
+            uri: String::new(),
             name: String::new(),
+            images: vec![],
+            popularity: 0,
             top_songs: vec![],
             albums: vec![],
             singles: vec![],
@@ -42,7 +53,10 @@ impl Artist {
     pub fn new(id: String) -> Self {
         Self {
             id,
+            uri: String::new(),
             name: String::new(),
+            images: vec![],
+            popularity: 0,
             top_songs: vec![],
             albums: vec![],
             singles: vec![],
@@ -52,70 +66,61 @@ impl Artist {
         }
     }
 
+    pub async fn prefetch(spotify_client: &mut SpotifyClient, ids: &[String]) -> AppResult<()> {
+        spotify_client
+            .get_cached_batch::<ArtistObject>("artists", ids, MAX_BATCH_IDS)
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn refresh(&mut self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
         let url = format!("artists/{}", self.id);
-        let resposne = spotify_client.get(&url).await?;
-        let json = resposne.json::<Value>().await?;
-
-        let name = json.get_string_or_default("name");
-        let genre_values = json.get_array_or_default("genres");
-        let mut genres: Vec<String> = vec![];
-        let mut followers = String::new();
-
-        for value in genre_values {
-            if let Value::String(genre) = value {
-                genres.push(Self::capitalize_genre(genre));
-            }
-        }
+        let artist = spotify_client.get_cached_json::<ArtistObject>(&url).await?;
 
-        let monthly_listeners = self.fetch_monthly_listeners().await?;
+        let genres: Vec<String> = artist
+            .genres
+            .into_iter()
+            .map(Self::capitalize_genre)
+            .collect();
+        let followers = artist.followers.total.to_formatted_string(&Locale::en);
 
-        if let Some(value) = json.get("followers") {
-            followers = value
-                .get_number_or_default("total")
-                .to_formatted_string(&Locale::en)
-        }
+        let monthly_listeners = self.fetch_monthly_listeners(spotify_client).await?;
 
         let url = format!("artists/{}/top-tracks", self.id);
-        let response = spotify_client.get(&url).await?;
-        let json = response.json::<Value>().await?;
-        let song_values = json.get_array_or_default("tracks");
-        let mut top_songs: Vec<NameAndId> = vec![];
-
-        for song in song_values {
-            let song_name = song.get_string_or_default("name");
-            let song_id = song.get_string_or_default("id");
-
-            top_songs.push((song_name, song_id));
-        }
+        let top_tracks = spotify_client
+            .get_cached_json::<TopTracksResponse>(&url)
+            .await?;
+        let top_songs: Vec<NameAndId> = top_tracks
+            .tracks
+            .into_iter()
+            .map(|track| (track.track.name, track.track.id.unwrap_or_default()))
+            .collect();
 
         let url = format!("artists/{}/albums?limit=50&include_groups=album", self.id);
-        let response = spotify_client.get(&url).await?;
-        let json = response.json::<Value>().await?;
-        let album_values = json.get_array_or_default("items");
-        let mut albums: Vec<NameAndId> = vec![];
-
-        for album in album_values {
-            let album_name = album.get_string_or_default("name");
-            let album_id = album.get_string_or_default("id");
-
-            albums.push((album_name, album_id))
-        }
+        let albums = Pager::<SimplifiedAlbumObject>::new(url)
+            .with_cache()
+            .collect_all(spotify_client)
+            .await?;
+        let albums: Vec<NameAndId> = albums
+            .into_iter()
+            .map(|album| (album.name, album.id.unwrap_or_default()))
+            .collect();
 
         let url = format!("artists/{}/albums?limit=50&include_groups=single", self.id);
-        let response = spotify_client.get(&url).await?;
-        let json = response.json::<Value>().await?;
-        let single_values = json.get_array_or_default("items");
-        let mut singles: Vec<NameAndId> = vec![];
-
-        for single in single_values {
-            let single_name = single.get_string_or_default("name");
-            let single_id = single.get_string_or_default("id");
-
-            singles.push((single_name, single_id));
-        }
-
-        self.name = name;
+        let singles = Pager::<SimplifiedAlbumObject>::new(url)
+            .with_cache()
+            .collect_all(spotify_client)
+            .await?;
+        let singles: Vec<NameAndId> = singles
+            .into_iter()
+            .map(|single| (single.name, single.id.unwrap_or_default()))
+            .collect();
+
+        self.name = artist.artist.name;
+        self.uri = artist.artist.uri.unwrap_or_default();
+        self.images = artist.images;
+        self.popularity = artist.popularity;
         self.top_songs = top_songs;
         self.albums = albums;
         self.singles = singles;
@@ -130,13 +135,24 @@ impl Artist {
         self.name.is_empty()
     }
 
-    async fn fetch_monthly_listeners(&self) -> AppResult<String> {
-        let mut monthly_listeners = String::new();
-        let url = format!("https://open.spotify.com/artist/{}", self.id);
-        let response = get(&url).await?;
-        let text = response.text().await?;
+    async fn fetch_monthly_listeners(&self, spotify_client: &SpotifyClient) -> AppResult<String> {
+        let key = format!("artist/{}/monthly-listeners", self.id);
+        let url = format!("{}/artist/{}", spotify_client.config.get_web_url(), self.id);
+
+        spotify_client
+            .cache
+            .get_or_fetch(&key, || async {
+                let response = spotify_client.http_client.get(&url).send().await?;
+                let text = response.text().await?;
 
-        let document = Html::parse_document(&text);
+                Self::parse_monthly_listeners(&text)
+            })
+            .await
+    }
+
+    fn parse_monthly_listeners(text: &str) -> AppResult<String> {
+        let mut monthly_listeners = String::new();
+        let document = Html::parse_document(text);
         let selector = Selector::parse(r#"#main [data-testid="monthly-listeners-label"]"#)
             .map_err(|_| Error::msg("invalid selector"))?;
         let elements: Vec<_> = document.select(&selector).collect();
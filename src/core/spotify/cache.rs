@@ -0,0 +1,335 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    future::Future,
+    io::Write,
+    path::Path,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex as AsyncMutex;
+
+use crate::{
+    core::{app::AppResult, config::Config},
+    utils::{directory::get_home_dir, time::get_unix_time},
+};
+
+const MAX_ENTRIES: usize = 2_000;
+const SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    body: String,
+    cached_at: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    api_url: String,
+    user_id: String,
+    entries: HashMap<String, CacheEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EntityCache {
+    entries: Arc<Mutex<HashMap<String, CacheEntry>>>,
+    in_flight: Arc<Mutex<HashMap<String, Arc<AsyncMutex<()>>>>>,
+    ttl: Duration,
+    is_persisted: bool,
+    api_url: String,
+    user_id: Arc<Mutex<Option<String>>>,
+    changed_at: Arc<Mutex<Option<Instant>>>,
+}
+
+impl EntityCache {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            entries: Arc::new(Mutex::new(HashMap::new())),
+            in_flight: Arc::new(Mutex::new(HashMap::new())),
+            ttl: config.get_cache_ttl(),
+            is_persisted: config.persist_cache.unwrap_or_default(),
+            api_url: config.get_api_url(),
+            user_id: Arc::new(Mutex::new(None)),
+            changed_at: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.ttl.is_zero()
+    }
+
+    pub fn is_persisted(&self) -> bool {
+        self.is_enabled() && self.is_persisted
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        let entries = self.lock_entries();
+        let entry = entries.get(key)?;
+
+        match self.is_fresh(entry) {
+            true => Some(entry.body.clone()),
+            false => None,
+        }
+    }
+
+    pub fn insert(&self, key: &str, body: String) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let mut entries = self.lock_entries();
+
+        if entries.len() >= MAX_ENTRIES && !entries.contains_key(key) {
+            entries.retain(|_, entry| self.is_fresh(entry));
+
+            if let Some(oldest_key) = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.cached_at)
+                .map(|(key, _)| key.clone())
+            {
+                entries.remove(&oldest_key);
+            }
+        }
+
+        entries.insert(
+            key.to_string(),
+            CacheEntry {
+                body,
+                cached_at: get_unix_time(),
+            },
+        );
+
+        lock(&self.changed_at).get_or_insert_with(Instant::now);
+    }
+
+    pub fn remove(&self, key: &str) {
+        self.lock_entries().remove(key);
+    }
+
+    pub async fn get_or_fetch<F, T>(&self, key: &str, fetch: F) -> AppResult<String>
+    where
+        F: FnOnce() -> T,
+        T: Future<Output = AppResult<String>>,
+    {
+        if !self.is_enabled() {
+            return fetch().await;
+        }
+
+        if let Some(body) = self.get(key) {
+            return Ok(body);
+        }
+
+        let key_lock = self.get_key_lock(key);
+        let _guard = key_lock.lock().await;
+
+        if let Some(body) = self.get(key) {
+            return Ok(body);
+        }
+
+        let result = fetch().await;
+
+        if let Ok(body) = &result {
+            self.insert(key, body.clone());
+        }
+
+        self.release_key_lock(key, &key_lock);
+
+        result
+    }
+
+    pub async fn get_or_fetch_many<F, T>(
+        &self,
+        keys: &[String],
+        fetch: F,
+    ) -> AppResult<HashMap<String, String>>
+    where
+        F: FnOnce(Vec<String>) -> T,
+        T: Future<Output = AppResult<HashMap<String, String>>>,
+    {
+        let mut keys = keys.to_vec();
+
+        keys.sort();
+        keys.dedup();
+
+        if !self.is_enabled() {
+            return fetch(keys).await;
+        }
+
+        let (mut bodies, missing_keys) = self.split_cached(keys);
+
+        if missing_keys.is_empty() {
+            return Ok(bodies);
+        }
+
+        let mut key_locks = vec![];
+
+        for key in &missing_keys {
+            let key_lock = self.get_key_lock(key);
+            let guard = key_lock.clone().lock_owned().await;
+
+            key_locks.push((key.clone(), key_lock, guard));
+        }
+
+        let (cached_bodies, missing_keys) = self.split_cached(missing_keys);
+
+        bodies.extend(cached_bodies);
+
+        let result = match missing_keys.is_empty() {
+            true => Ok(HashMap::new()),
+            false => fetch(missing_keys).await,
+        };
+
+        if let Ok(fetched_bodies) = &result {
+            for (key, body) in fetched_bodies {
+                self.insert(key, body.clone());
+            }
+        }
+
+        for (key, key_lock, guard) in key_locks {
+            drop(guard);
+            self.release_key_lock(&key, &key_lock);
+        }
+
+        bodies.extend(result?);
+
+        Ok(bodies)
+    }
+
+    pub fn save(&self) -> AppResult<()> {
+        let user_id = match lock(&self.user_id).clone() {
+            Some(user_id) if self.is_persisted() => user_id,
+            _ => return Ok(()),
+        };
+
+        let entries: HashMap<String, CacheEntry> = self
+            .lock_entries()
+            .iter()
+            .filter(|(_, entry)| self.is_fresh(entry))
+            .map(|(key, entry)| (key.clone(), entry.clone()))
+            .collect();
+
+        let cache_file = CacheFile {
+            api_url: self.api_url.clone(),
+            user_id,
+            entries,
+        };
+
+        *lock(&self.changed_at) = None;
+
+        let data = serde_json::to_string(&cache_file)?;
+        let file_path = Self::get_file_path()?;
+
+        if let Some(parent) = Path::new(&file_path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = File::create(file_path)?;
+        file.write_all(data.as_bytes())?;
+
+        Ok(())
+    }
+
+    pub fn save_if_due(&self) {
+        let is_due = match *lock(&self.changed_at) {
+            Some(changed_at) => changed_at.elapsed() >= SAVE_INTERVAL,
+            None => false,
+        };
+
+        if is_due {
+            if let Err(error) = self.save() {
+                warn!("Failed to save entity cache: {}", error);
+            }
+        }
+    }
+
+    pub fn load(&self, user_id: &str) -> AppResult<()> {
+        *lock(&self.user_id) = Some(user_id.to_string());
+
+        let file_path = Self::get_file_path()?;
+
+        if !Path::new(&file_path).exists() {
+            return Ok(());
+        }
+
+        let data = fs::read_to_string(&file_path)?;
+        let cache_file: CacheFile = serde_json::from_str(&data)?;
+
+        if cache_file.api_url != self.api_url || cache_file.user_id != user_id {
+            return Ok(());
+        }
+
+        let mut entries = self.lock_entries();
+
+        for (key, entry) in cache_file.entries {
+            if self.is_fresh(&entry) {
+                entries.entry(key).or_insert(entry);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn split_cached(&self, keys: Vec<String>) -> (HashMap<String, String>, Vec<String>) {
+        let mut bodies = HashMap::new();
+        let mut missing_keys = vec![];
+
+        for key in keys {
+            match self.get(&key) {
+                Some(body) => {
+                    bodies.insert(key, body);
+                }
+                None => missing_keys.push(key),
+            }
+        }
+
+        (bodies, missing_keys)
+    }
+
+    fn is_fresh(&self, entry: &CacheEntry) -> bool {
+        get_unix_time().saturating_sub(entry.cached_at) < self.ttl.as_secs()
+    }
+
+    fn get_key_lock(&self, key: &str) -> Arc<AsyncMutex<()>> {
+        self.lock_in_flight()
+            .entry(key.to_string())
+            .or_insert_with(|| Arc::new(AsyncMutex::new(())))
+            .clone()
+    }
+
+    fn release_key_lock(&self, key: &str, key_lock: &Arc<AsyncMutex<()>>) {
+        let mut in_flight = self.lock_in_flight();
+
+        if let Some(current_lock) = in_flight.get(key) {
+            if Arc::ptr_eq(current_lock, key_lock) {
+                in_flight.remove(key);
+            }
+        }
+    }
+
+    fn lock_entries(&self) -> MutexGuard<'_, HashMap<String, CacheEntry>> {
+        lock(&self.entries)
+    }
+
+    fn lock_in_flight(&self) -> MutexGuard<'_, HashMap<String, Arc<AsyncMutex<()>>>> {
+        lock(&self.in_flight)
+    }
+
+    fn get_file_path() -> AppResult<String> {
+        let home_dir = get_home_dir()?;
+
+        Ok(format!(
+            "{}/.config/spotify-client-tui/cache.json",
+            home_dir
+        ))
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    match mutex.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
@@ -3,39 +3,106 @@ use std::{
     fs::{self, File},
     io::Write,
     path::Path,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 
-use async_recursion::async_recursion;
 use base64::{engine::general_purpose, Engine};
 use color_eyre::eyre::eyre;
-use log::error;
-use reqwest::{Client, Response, Url};
-use serde::{Deserialize, Serialize};
+use log::{error, warn};
+use rand::{thread_rng, Rng};
+use reqwest::{header::RETRY_AFTER, Client, Method, RequestBuilder, Response, StatusCode, Url};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value;
+use tokio::{sync::Mutex as AsyncMutex, time::sleep};
 
 use crate::{
-    core::{app::AppResult, config::Config},
-    utils::directory::get_home_dir,
+    auth::pkce::Pkce,
+    core::{
+        app::AppResult,
+        config::{AuthFlow, Config},
+    },
+    utils::{
+        directory::get_home_dir, random::get_random_string, string::to_file_name,
+        time::get_unix_time,
+    },
 };
 
+use super::{
+    cache::EntityCache,
+    error::SpotifyError,
+    models::{error::ErrorResponse, playlist::PublicUserObject},
+    request_stats::{EndpointStats, RequestStats},
+};
+
+const STATE_LENGTH: usize = 32;
+const TOKEN_EXPIRY_MARGIN_SECS: u64 = 60;
+const MAX_AUTH_RETRIES: u32 = 1;
+const MAX_RETRIES: u32 = 3;
+const MAX_RETRY_AFTER_SECS: u64 = 5;
+const BASE_BACKOFF_MS: u64 = 250;
+const MAX_BACKOFF_MS: u64 = 4_000;
+const REQUEST_TIMEOUT_SECS: u64 = 10;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Credentials {
     access_token: String,
     refresh_token: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expires_in: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    issued_at: Option<u64>,
+}
+
+impl Credentials {
+    pub fn is_expiring(&self) -> bool {
+        match (self.expires_in, self.issued_at) {
+            (Some(expires_in), Some(issued_at)) => {
+                get_unix_time() + TOKEN_EXPIRY_MARGIN_SECS >= issued_at + expires_in
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: Option<String>,
+    refresh_token: Option<String>,
+    expires_in: Option<u64>,
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+impl TokenResponse {
+    fn get_error_message(&self) -> String {
+        match (&self.error, &self.error_description) {
+            (Some(error), Some(description)) => format!("{}: {}", error, description),
+            (Some(error), None) => error.clone(),
+            _ => "No access token returned".to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct SpotifyClient {
     pub config: Config,
-    pub credentials: Option<Credentials>,
+    credentials: Arc<Mutex<Option<Credentials>>>,
+    pub credentials_path: String,
     pub code: Option<String>,
     pub auth_url: String,
     pub http_client: Client,
+    pub pkce: Pkce,
+    pub state: String,
+    pub request_stats: RequestStats,
+    pub cache: EntityCache,
+    rate_limited_until: Arc<Mutex<Option<Instant>>>,
+    refresh_lock: Arc<AsyncMutex<()>>,
 }
 
 impl SpotifyClient {
     pub fn new(config: Config) -> AppResult<Self> {
-        let mut url = Url::parse("https://accounts.spotify.com/authorize")?;
+        let mut url = Url::parse(&format!("{}/authorize", config.get_accounts_url()))?;
 
         url.query_pairs_mut().append_pair("response_type", "code");
 
@@ -78,98 +145,98 @@ impl SpotifyClient {
             }
         }
 
+        let state = get_random_string(STATE_LENGTH);
+        let pkce = Pkce::new();
+
+        url.query_pairs_mut().append_pair("state", &state);
+
+        match config.get_auth_flow() {
+            AuthFlow::Pkce => {
+                url.query_pairs_mut()
+                    .append_pair("code_challenge_method", "S256")
+                    .append_pair("code_challenge", &pkce.code_challenge);
+            }
+            AuthFlow::ClientSecret => {
+                if config.client_secret.is_none() {
+                    error_message += "No Client Secret provided.";
+
+                    error!("{}", error_message);
+                    return Err(eyre!(error_message));
+                }
+            }
+        }
+
         let mut credentials: Option<Credentials> = None;
-        let file_path = Self::get_file_path()?;
+        let credentials_path = Self::get_file_path(&config)?;
+
+        Self::migrate_legacy_credentials(&config, &credentials_path)?;
 
-        if Path::new(&file_path).exists() {
-            let data = fs::read_to_string(&file_path)?;
+        if Path::new(&credentials_path).exists() {
+            let data = fs::read_to_string(&credentials_path)?;
             let credentials_data: Credentials = serde_json::from_str(&data)?;
 
             credentials = Some(credentials_data);
         }
 
+        let cache = EntityCache::new(&config);
+
         Ok(Self {
             config,
-            credentials,
+            credentials: Arc::new(Mutex::new(credentials)),
+            credentials_path,
             code: None,
             auth_url: url.to_string(),
-            http_client: Client::new(),
+            http_client: Client::builder()
+                .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+                .build()?,
+            pkce,
+            state,
+            request_stats: RequestStats::default(),
+            cache,
+            rate_limited_until: Arc::new(Mutex::new(None)),
+            refresh_lock: Arc::new(AsyncMutex::new(())),
         })
     }
 
     pub async fn set_code_and_access_token(&mut self, code: String) -> AppResult<()> {
         self.code = Some(code.clone());
 
-        if let Some(client_id) = self.config.client_id.clone() {
-            if let Some(client_secret) = self.config.client_secret.clone() {
-                if let Some(redirect_uri) = self.config.redirect_uri.clone() {
-                    let auth_header = format!(
-                        "Basic {}",
-                        general_purpose::STANDARD.encode(format!(
-                            "{}:{}",
-                            client_id.clone(),
-                            client_secret.clone()
-                        ))
-                    );
-
-                    let mut body = HashMap::<&str, &str>::new();
-
-                    body.insert("code", &code);
-                    body.insert("grant_type", "authorization_code");
-                    body.insert("redirect_uri", &redirect_uri);
-
-                    let response = self
-                        .http_client
-                        .post("https://accounts.spotify.com/api/token")
-                        .header("Content-Type", "application/x-www-form-urlencoded")
-                        .header("Authorization", auth_header)
-                        .form(&body)
-                        .send()
-                        .await?
-                        .json::<Value>()
-                        .await?;
-
-                    let mut access_token: Option<String> = None;
-                    let mut refresh_token: Option<String> = None;
-
-                    if let Some(access_token_value) = response.get("access_token") {
-                        match access_token_value.to_owned() {
-                            Value::String(access_token_value) => {
-                                access_token = Some(access_token_value);
-                            }
-                            _ => {}
-                        }
-                    }
+        if let Some(redirect_uri) = self.config.redirect_uri.clone() {
+            let mut body = HashMap::<&str, String>::new();
 
-                    if let Some(refresh_token_value) = response.get("refresh_token") {
-                        match refresh_token_value.to_owned() {
-                            Value::String(refresh_token_value) => {
-                                refresh_token = Some(refresh_token_value);
-                            }
-                            _ => {}
-                        }
-                    }
+            body.insert("code", code);
+            body.insert("grant_type", "authorization_code".to_string());
+            body.insert("redirect_uri", redirect_uri);
 
-                    if let Some(access_token) = access_token {
-                        if let Some(refresh_token) = refresh_token {
-                            let credentials = Credentials {
-                                refresh_token,
-                                access_token,
-                            };
+            if self.config.get_auth_flow() == AuthFlow::Pkce {
+                body.insert("code_verifier", self.pkce.code_verifier.clone());
+            }
 
-                            let data = serde_json::to_string_pretty(&credentials)?;
-                            let file_path = Self::get_file_path()?;
+            let response = self.request_token(body).await?;
 
-                            if let Some(parent) = Path::new(&file_path).parent() {
-                                fs::create_dir_all(parent)?;
-                            }
+            match (
+                response.access_token.clone(),
+                response.refresh_token.clone(),
+            ) {
+                (Some(access_token), Some(refresh_token)) => {
+                    let credentials = Credentials {
+                        access_token,
+                        refresh_token,
+                        expires_in: response.expires_in,
+                        issued_at: Some(get_unix_time()),
+                    };
 
-                            let mut file = File::create(file_path)?;
-                            file.write_all(data.as_bytes())?;
+                    self.save_credentials(&credentials)?;
+                    self.set_credentials(Some(credentials));
+                }
+                _ => {
+                    let error_message = format!(
+                        "Failed to get access token: {}",
+                        response.get_error_message()
+                    );
 
-                            self.credentials = Some(credentials);
-                        }
-                    }
+                    error!("{}", error_message);
+                    return Err(eyre!(error_message));
                 }
             }
         }
@@ -178,69 +245,58 @@ impl SpotifyClient {
     }
 
     pub async fn refresh_auth_token(&mut self) -> AppResult<()> {
-        if let Some(credentials) = self.credentials.clone() {
-            if let Some(client_id) = self.config.client_id.clone() {
-                if let Some(client_secret) = self.config.client_secret.clone() {
-                    let auth_header = format!(
-                        "Basic {}",
-                        general_purpose::STANDARD.encode(format!(
-                            "{}:{}",
-                            client_id.clone(),
-                            client_secret.clone()
-                        ))
-                    );
+        if let Some(credentials) = self.get_credentials() {
+            let mut body = HashMap::<&str, String>::new();
 
-                    let mut body = HashMap::<&str, &str>::new();
-
-                    body.insert("grant_type", "refresh_token");
-                    body.insert("refresh_token", &credentials.refresh_token);
-                    body.insert("client_id", &client_id);
-
-                    let response = self
-                        .http_client
-                        .post("https://accounts.spotify.com/api/token")
-                        .header("Content-Type", "application/x-www-form-urlencoded")
-                        .header("Authorization", auth_header)
-                        .form(&body)
-                        .send()
-                        .await?
-                        .json::<Value>()
-                        .await?;
-
-                    let mut access_token = credentials.access_token;
-
-                    if let Some(access_token_value) = response.get("access_token") {
-                        match access_token_value.to_owned() {
-                            Value::String(access_token_value) => access_token = access_token_value,
-                            _ => {}
-                        }
-                    }
+            body.insert("grant_type", "refresh_token".to_string());
+            body.insert("refresh_token", credentials.refresh_token.clone());
 
-                    let new_credentials = Credentials {
-                        refresh_token: credentials.refresh_token,
-                        access_token,
-                    };
+            let response = self.request_token(body).await?;
 
-                    let data = serde_json::to_string_pretty(&new_credentials)?;
-                    let file_path = Self::get_file_path()?;
+            if response.error.as_deref() == Some("invalid_grant") {
+                warn!(
+                    "Refresh token was rejected: {}",
+                    response.get_error_message()
+                );
 
-                    if let Some(parent) = Path::new(&file_path).parent() {
-                        fs::create_dir_all(parent)?;
-                    }
+                self.clear_credentials()?;
 
-                    let mut file = File::create(file_path)?;
-                    file.write_all(data.as_bytes())?;
+                return Err(SpotifyError::AuthRevoked.into());
+            }
+
+            let access_token = match response.access_token.clone() {
+                Some(access_token) => access_token,
+                None => {
+                    let error_message = format!(
+                        "Failed to refresh access token: {}",
+                        response.get_error_message()
+                    );
 
-                    self.credentials = Some(new_credentials);
+                    error!("{}", error_message);
+                    return Err(eyre!(error_message));
                 }
-            }
+            };
+
+            let new_credentials = Credentials {
+                access_token,
+                refresh_token: response.refresh_token.unwrap_or(credentials.refresh_token),
+                expires_in: response.expires_in,
+                issued_at: Some(get_unix_time()),
+            };
+
+            self.save_credentials(&new_credentials)?;
+            self.set_credentials(Some(new_credentials));
         }
 
         Ok(())
     }
 
+    pub fn has_credentials(&self) -> bool {
+        self.get_credentials().is_some()
+    }
+
     pub fn get_auth_header(&self) -> AppResult<String> {
-        match self.credentials.clone() {
+        match self.get_credentials() {
             Some(credentials) => Ok(format!("Bearer {}", credentials.access_token)),
             None => {
                 let error_message = "No credentials set";
@@ -251,97 +307,546 @@ impl SpotifyClient {
         }
     }
 
-    #[async_recursion]
     pub async fn get(&mut self, route: &str) -> AppResult<Response> {
-        let auth_header = self.get_auth_header()?;
-        let url = format!("https://api.spotify.com/v1/{}", route);
+        self.send(Method::GET, route, None).await
+    }
 
-        let response = self
-            .http_client
-            .get(url)
-            .header("Authorization", auth_header)
-            .send()
+    pub async fn put(&mut self, route: &str, body: Option<&Value>) -> AppResult<Response> {
+        self.send(Method::PUT, route, body).await
+    }
+
+    pub async fn post(&mut self, route: &str, body: Option<&Value>) -> AppResult<Response> {
+        self.send(Method::POST, route, body).await
+    }
+
+    pub async fn delete(&mut self, route: &str, body: Option<&Value>) -> AppResult<Response> {
+        self.send(Method::DELETE, route, body).await
+    }
+
+    pub async fn get_json<T: DeserializeOwned>(&mut self, route: &str) -> AppResult<T> {
+        let response = self.get(route).await?;
+
+        Self::parse_json(route, response).await
+    }
+
+    pub async fn load_cache(&mut self) -> AppResult<()> {
+        if !self.cache.is_persisted() {
+            return Ok(());
+        }
+
+        let user = self.get_json::<PublicUserObject>("me").await?;
+
+        self.cache.load(&user.id)
+    }
+
+    pub async fn get_cached_json<T: DeserializeOwned>(&mut self, route: &str) -> AppResult<T> {
+        let key = self.get_route(route);
+        let cache = self.cache.clone();
+        let text = cache
+            .get_or_fetch(&key, || async {
+                let response = self.get(route).await?;
+
+                Ok(response.text().await?)
+            })
             .await?;
 
-        let status = response.status();
+        let result = Self::parse_text(route, &text);
+
+        if result.is_err() {
+            cache.remove(&key);
+        }
+
+        result
+    }
+
+    pub async fn get_cached_batch<T: DeserializeOwned>(
+        &mut self,
+        route: &str,
+        ids: &[String],
+        max_ids: usize,
+    ) -> AppResult<Vec<T>> {
+        let prefix = format!("{}/", route);
+        let keys: Vec<String> = ids
+            .iter()
+            .filter(|id| !id.is_empty())
+            .map(|id| format!("{}{}", prefix, id))
+            .collect();
+
+        let cache = self.cache.clone();
+        let bodies = cache
+            .get_or_fetch_many(&keys, |missing_keys| async move {
+                let missing_ids: Vec<&str> = missing_keys
+                    .iter()
+                    .map(|key| key.strip_prefix(&prefix).unwrap_or(key))
+                    .collect();
+                let mut bodies = HashMap::new();
+
+                for chunk in missing_ids.chunks(max_ids.max(1)) {
+                    let url = format!("{}?ids={}", route, chunk.join(","));
+                    let mut response = self.get_json::<HashMap<String, Vec<Value>>>(&url).await?;
+
+                    for item in response.remove(route).unwrap_or_default() {
+                        if let Some(id) = item.get("id").and_then(Value::as_str) {
+                            bodies.insert(format!("{}{}", prefix, id), item.to_string());
+                        }
+                    }
+                }
+
+                Ok(bodies)
+            })
+            .await?;
 
-        if status == 401 {
-            self.refresh_auth_token().await?;
+        let mut items = vec![];
 
-            return self.get(route).await;
+        for key in keys {
+            if let Some(body) = bodies.get(&key) {
+                match Self::parse_text::<T>(&key, body) {
+                    Ok(item) => items.push(item),
+                    Err(error) => {
+                        cache.remove(&key);
+
+                        return Err(error);
+                    }
+                }
+            }
         }
 
-        Ok(response)
+        Ok(items)
     }
 
-    #[async_recursion]
-    pub async fn put(&mut self, route: &str, body: Option<&Value>) -> AppResult<Response> {
-        let auth_header = self.get_auth_header()?;
-        let url = format!("https://api.spotify.com/v1/{}", route);
+    pub async fn get_cached_flags(
+        &mut self,
+        route: &str,
+        ids: &[String],
+        max_ids: usize,
+    ) -> AppResult<HashMap<String, bool>> {
+        let prefix = format!("{}/", route);
+        let keys: Vec<String> = ids
+            .iter()
+            .filter(|id| !id.is_empty())
+            .map(|id| format!("{}{}", prefix, id))
+            .collect();
+
+        let cache = self.cache.clone();
+        let bodies = cache
+            .get_or_fetch_many(&keys, |missing_keys| async move {
+                let missing_ids: Vec<&str> = missing_keys
+                    .iter()
+                    .map(|key| key.strip_prefix(&prefix).unwrap_or(key))
+                    .collect();
+                let mut bodies = HashMap::new();
+
+                for chunk in missing_ids.chunks(max_ids.max(1)) {
+                    let url = format!("{}?ids={}", route, chunk.join(","));
+                    let flags = self.get_json::<Vec<bool>>(&url).await?;
+
+                    for (id, flag) in chunk.iter().zip(flags) {
+                        bodies.insert(format!("{}{}", prefix, id), flag.to_string());
+                    }
+                }
 
-        let mut request = self
-            .http_client
-            .put(&url)
-            .header("Authorization", auth_header);
+                Ok(bodies)
+            })
+            .await?;
 
-        match body {
-            Some(body) => {
-                request = request.json(body);
+        Ok(ids
+            .iter()
+            .filter_map(|id| {
+                let body = bodies.get(&format!("{}/{}", route, id))?;
+
+                Some((id.clone(), body == "true"))
+            })
+            .collect())
+    }
+
+    pub fn set_cached_flag(&self, route: &str, id: &str, flag: bool) {
+        self.cache
+            .insert(&format!("{}/{}", route, id), flag.to_string());
+    }
+
+    pub async fn parse_json<T: DeserializeOwned>(route: &str, response: Response) -> AppResult<T> {
+        let text = response.text().await?;
+
+        Self::parse_text(route, &text)
+    }
+
+    fn parse_text<T: DeserializeOwned>(route: &str, text: &str) -> AppResult<T> {
+        match serde_json::from_str::<T>(text) {
+            Ok(value) => Ok(value),
+            Err(parse_error) => {
+                error!("Failed to parse response from `{}`: {}", route, parse_error);
+
+                Err(SpotifyError::InvalidResponse {
+                    endpoint: route.to_string(),
+                    message: parse_error.to_string(),
+                }
+                .into())
             }
-            None => {
-                request = request.header("Content-Length", 0);
+        }
+    }
+
+    pub fn get_endpoint_stats(&self) -> Vec<(String, EndpointStats)> {
+        self.request_stats.get_endpoint_stats()
+    }
+
+    async fn send(
+        &mut self,
+        method: Method,
+        route: &str,
+        body: Option<&Value>,
+    ) -> AppResult<Response> {
+        if let Some(credentials) = self.get_credentials() {
+            if credentials.is_expiring() {
+                self.refresh_auth_token_once(&credentials.access_token)
+                    .await?;
             }
         }
 
-        let response = request.send().await?;
-        let status = response.status();
+        let endpoint = RequestStats::get_endpoint_key(&method, &self.get_route(route));
+        let is_idempotent = method != Method::POST;
+        let mut auth_retries = 0;
+        let mut retries = 0;
+
+        loop {
+            self.wait_for_rate_limit(&endpoint).await?;
+            self.request_stats.record_request(&endpoint);
+
+            let auth_header = self.get_auth_header()?;
+            let result = self
+                .build_request(method.clone(), route, body)?
+                .send()
+                .await;
+
+            let response = match result {
+                Ok(response) => response,
+                Err(request_error) => {
+                    let can_retry =
+                        request_error.is_connect() || (request_error.is_timeout() && is_idempotent);
+
+                    if can_retry && retries < MAX_RETRIES {
+                        retries += 1;
+                        self.request_stats.record_retry(&endpoint);
+                        sleep(Self::get_backoff(retries)).await;
+
+                        continue;
+                    }
+
+                    warn!("Request to `{}` failed: {}", endpoint, request_error);
+                    self.request_stats.record_error(&endpoint);
+
+                    return Err(SpotifyError::Network {
+                        message: request_error.to_string(),
+                    }
+                    .into());
+                }
+            };
+
+            let status = response.status();
+
+            if status.is_success() {
+                return Ok(response);
+            }
+
+            if status == StatusCode::UNAUTHORIZED && auth_retries < MAX_AUTH_RETRIES {
+                auth_retries += 1;
+
+                let access_token = auth_header.trim_start_matches("Bearer ");
+
+                self.refresh_auth_token_once(access_token).await?;
+
+                continue;
+            }
 
-        if status == 401 {
-            self.refresh_auth_token().await?;
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                let retry_after = Self::get_retry_after(&response);
+                let delay = match retry_after {
+                    Some(retry_after) => Duration::from_secs(retry_after),
+                    None => Self::get_backoff(retries + 1),
+                };
 
-            return self.put(route, body).await;
+                warn!("Rate limited on `{}`, retry after {:?}", endpoint, delay);
+                self.request_stats.record_rate_limited(&endpoint);
+                self.set_rate_limited_until(Instant::now() + delay);
+
+                if retries < MAX_RETRIES && delay.as_secs() <= MAX_RETRY_AFTER_SECS {
+                    retries += 1;
+                    self.request_stats.record_retry(&endpoint);
+
+                    continue;
+                }
+
+                self.request_stats.record_error(&endpoint);
+
+                return Err(SpotifyError::RateLimited { retry_after }.into());
+            }
+
+            if status.is_server_error() && is_idempotent && retries < MAX_RETRIES {
+                retries += 1;
+                self.request_stats.record_retry(&endpoint);
+                sleep(Self::get_backoff(retries)).await;
+
+                continue;
+            }
+
+            warn!("Request to `{}` failed with status {}", endpoint, status);
+            self.request_stats.record_error(&endpoint);
+
+            return Err(Self::get_api_error(response).await.into());
         }
+    }
 
-        Ok(response)
+    async fn wait_for_rate_limit(&self, endpoint: &str) -> AppResult<()> {
+        let rate_limited_until = match self.rate_limited_until.lock() {
+            Ok(rate_limited_until) => *rate_limited_until,
+            Err(poisoned) => *poisoned.into_inner(),
+        };
+
+        if let Some(rate_limited_until) = rate_limited_until {
+            let remaining = rate_limited_until.saturating_duration_since(Instant::now());
+
+            if remaining > Duration::from_secs(MAX_RETRY_AFTER_SECS) {
+                self.request_stats.record_rate_limited(endpoint);
+                self.request_stats.record_error(endpoint);
+
+                return Err(SpotifyError::RateLimited {
+                    retry_after: Some(remaining.as_secs() + 1),
+                }
+                .into());
+            }
+
+            if !remaining.is_zero() {
+                sleep(remaining).await;
+            }
+        }
+
+        Ok(())
     }
 
-    #[async_recursion]
-    pub async fn post(&mut self, route: &str, body: Option<&Value>) -> AppResult<Response> {
+    fn set_rate_limited_until(&self, instant: Instant) {
+        match self.rate_limited_until.lock() {
+            Ok(mut rate_limited_until) => *rate_limited_until = Some(instant),
+            Err(poisoned) => *poisoned.into_inner() = Some(instant),
+        }
+    }
+
+    fn get_retry_after(response: &Response) -> Option<u64> {
+        response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+    }
+
+    fn get_backoff(attempt: u32) -> Duration {
+        let backoff = BASE_BACKOFF_MS
+            .saturating_mul(2u64.saturating_pow(attempt.saturating_sub(1)))
+            .min(MAX_BACKOFF_MS);
+        let jitter = thread_rng().gen_range(0..=backoff / 2);
+
+        Duration::from_millis(backoff / 2 + jitter)
+    }
+
+    async fn get_api_error(response: Response) -> SpotifyError {
+        let status = response.status();
+
+        match response.json::<ErrorResponse>().await {
+            Ok(body) => {
+                SpotifyError::from_status(status.as_u16(), body.error.message, body.error.reason)
+            }
+            Err(_) => SpotifyError::from_status(
+                status.as_u16(),
+                status
+                    .canonical_reason()
+                    .unwrap_or("Unknown error")
+                    .to_string(),
+                None,
+            ),
+        }
+    }
+
+    fn build_request(
+        &self,
+        method: Method,
+        route: &str,
+        body: Option<&Value>,
+    ) -> AppResult<RequestBuilder> {
         let auth_header = self.get_auth_header()?;
-        let url = format!("https://api.spotify.com/v1/{}", route);
+        let url = self.get_api_url(route);
+        let has_body = method != Method::GET;
 
         let mut request = self
             .http_client
-            .post(&url)
+            .request(method, &url)
             .header("Authorization", auth_header);
 
         match body {
             Some(body) => {
                 request = request.json(body);
             }
-            None => {
+            None if has_body => {
                 request = request.header("Content-Length", 0);
             }
+            None => {}
         }
 
-        let response = request.send().await?;
-        let status = response.status();
+        Ok(request)
+    }
+
+    async fn request_token(&self, mut body: HashMap<&str, String>) -> AppResult<TokenResponse> {
+        let client_id = match self.config.client_id.clone() {
+            Some(client_id) => client_id,
+            None => {
+                let error_message = "No Client ID provided";
 
-        if status == 401 {
-            self.refresh_auth_token().await?;
+                error!("{}", error_message);
+                return Err(eyre!(error_message));
+            }
+        };
+
+        let mut request = self
+            .http_client
+            .post(self.get_token_url())
+            .header("Content-Type", "application/x-www-form-urlencoded");
 
-            return self.put(route, body).await;
+        if self.config.get_auth_flow() == AuthFlow::ClientSecret {
+            let client_secret = self.config.client_secret.clone().unwrap_or_default();
+            let auth_header = format!(
+                "Basic {}",
+                general_purpose::STANDARD.encode(format!("{}:{}", client_id, client_secret))
+            );
+
+            request = request.header("Authorization", auth_header);
         }
 
+        body.insert("client_id", client_id);
+
+        let response = request
+            .form(&body)
+            .send()
+            .await?
+            .json::<TokenResponse>()
+            .await?;
+
         Ok(response)
     }
 
-    fn get_file_path() -> AppResult<String> {
+    fn get_api_url(&self, route: &str) -> String {
+        format!("{}/{}", self.config.get_api_url(), self.get_route(route))
+    }
+
+    fn get_route(&self, route: &str) -> String {
+        let api_url = self.config.get_api_url();
+
+        if let Some(route) = route.strip_prefix(api_url.as_str()) {
+            return route.trim_start_matches('/').to_string();
+        }
+
+        if let Ok(url) = Url::parse(route) {
+            let path = url.path();
+            let path = path
+                .split_once("/v1/")
+                .map(|(_, path)| path)
+                .unwrap_or(path);
+
+            return match url.query() {
+                Some(query) => format!("{}?{}", path.trim_start_matches('/'), query),
+                None => path.trim_start_matches('/').to_string(),
+            };
+        }
+
+        route.trim_start_matches('/').to_string()
+    }
+
+    fn get_token_url(&self) -> String {
+        format!("{}/api/token", self.config.get_accounts_url())
+    }
+
+    pub fn clear_credentials(&mut self) -> AppResult<()> {
+        self.set_credentials(None);
+
+        self.delete_credentials()
+    }
+
+    async fn refresh_auth_token_once(&mut self, access_token: &str) -> AppResult<()> {
+        let refresh_lock = self.refresh_lock.clone();
+        let _guard = refresh_lock.lock().await;
+
+        match self.get_credentials() {
+            Some(credentials) if credentials.access_token == access_token => {
+                self.refresh_auth_token().await
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn get_credentials(&self) -> Option<Credentials> {
+        match self.credentials.lock() {
+            Ok(credentials) => credentials.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    fn set_credentials(&self, new_credentials: Option<Credentials>) {
+        match self.credentials.lock() {
+            Ok(mut credentials) => *credentials = new_credentials,
+            Err(poisoned) => *poisoned.into_inner() = new_credentials,
+        }
+    }
+
+    fn save_credentials(&self, credentials: &Credentials) -> AppResult<()> {
+        let data = serde_json::to_string_pretty(credentials)?;
+
+        if let Some(parent) = Path::new(&self.credentials_path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = File::create(&self.credentials_path)?;
+        file.write_all(data.as_bytes())?;
+
+        Ok(())
+    }
+
+    fn delete_credentials(&self) -> AppResult<()> {
+        if Path::new(&self.credentials_path).exists() {
+            fs::remove_file(&self.credentials_path)?;
+        }
+
+        Ok(())
+    }
+
+    fn migrate_legacy_credentials(config: &Config, file_path: &str) -> AppResult<()> {
+        let legacy_file_path = format!(
+            "{}/.config/spotify-client-tui/credentials.json",
+            get_home_dir()?
+        );
+
+        if !config.is_default_accounts_url()
+            || Path::new(file_path).exists()
+            || !Path::new(&legacy_file_path).exists()
+        {
+            return Ok(());
+        }
+
+        if let Some(parent) = Path::new(file_path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::rename(legacy_file_path, file_path)?;
+
+        Ok(())
+    }
+
+    fn get_file_path(config: &Config) -> AppResult<String> {
         let home_dir = get_home_dir()?;
+        let file_name = to_file_name(&format!(
+            "{}-{}",
+            config.get_accounts_url(),
+            config.client_id.clone().unwrap_or_default()
+        ));
 
         Ok(format!(
-            "{}/.config/spotify-client-tui/credentials.json",
-            home_dir
+            "{}/.config/spotify-client-tui/credentials/{}.json",
+            home_dir, file_name
         ))
     }
 }
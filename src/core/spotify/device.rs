@@ -1,10 +1,13 @@
 use std::collections::HashMap;
 
-use serde_json::{json, Value};
+use serde_json::json;
 
-use crate::{core::app::AppResult, utils::value::GetOrDefault};
+use crate::core::{app::AppResult, output::CliError};
 
-use super::client::SpotifyClient;
+use super::{
+    client::SpotifyClient,
+    models::device::{DeviceObject, DevicesResponse},
+};
 
 #[derive(Debug, Clone)]
 pub struct Device {
@@ -23,30 +26,23 @@ impl Default for Device {
 
 impl Device {
     pub async fn refresh(&mut self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
-        let response = spotify_client.get("me/player/devices").await?;
-        let status = response.status();
+        let response = spotify_client
+            .get_json::<DevicesResponse>("me/player/devices")
+            .await?;
+        let mut available_devices = HashMap::new();
 
-        if status == 200 {
-            let mut available_devices = HashMap::new();
+        for device in response.devices {
+            let id = device.id.unwrap_or_default();
 
-            let json = response.json::<Value>().await?;
-            let devices_array = json.get_array_or_default("devices");
-
-            for device in devices_array {
-                let name = device.get_string_or_default("name");
-                let id = device.get_string_or_default("id");
-                let is_active = device.get_bool_or_default("is_active");
-
-                if is_active {
-                    self.current_device_name = Some(name.clone());
-                }
-
-                available_devices.insert(name, id);
+            if device.is_active {
+                self.current_device_name = Some(device.name.clone());
             }
 
-            self.available_devices = available_devices;
+            available_devices.insert(device.name, id);
         }
 
+        self.available_devices = available_devices;
+
         Ok(())
     }
 
@@ -59,9 +55,9 @@ impl Device {
                     devices_names.push(format!("* {} *", device.0));
                     continue;
                 }
-
-                devices_names.push(device.0);
             }
+
+            devices_names.push(device.0);
         }
 
         devices_names
@@ -82,34 +78,85 @@ impl Device {
         Ok(())
     }
 
-    pub async fn print_devices(&self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
-        let response = spotify_client.get("me/player/devices").await?;
-        let status = response.status();
-
-        if status == 200 {
-            let response_json = response.json::<Value>().await?;
-
-            if let Some(devices) = response_json.get("devices") {
-                if let Value::Array(devices) = devices {
-                    for device in devices {
-                        if let Some(id) = device.get("id") {
-                            if let Value::String(id) = id {
-                                println!("id: {}", id);
-                            }
-                        }
-
-                        if let Some(name) = device.get("name") {
-                            if let Value::String(name) = name {
-                                println!("name: {}", name);
-                            }
-                        }
-
-                        println!();
-                    }
-                }
+    pub fn find_device_id(&self, name: &str) -> AppResult<String> {
+        let name = name.trim().to_lowercase();
+
+        if let Some((_, id)) = self
+            .available_devices
+            .iter()
+            .find(|(device_name, _)| device_name.to_lowercase() == name)
+        {
+            return Ok(id.clone());
+        }
+
+        let matches: Vec<(&String, &String)> = self
+            .available_devices
+            .iter()
+            .filter(|(device_name, _)| device_name.to_lowercase().contains(&name))
+            .collect();
+
+        match matches.as_slice() {
+            [(_, id)] => Ok(id.to_string()),
+            [] => Err(CliError::InvalidArgument {
+                message: format!(
+                    "No device matches `{}`. Available devices: {}",
+                    name,
+                    self.get_device_names_string()
+                ),
             }
+            .into()),
+            _ => Err(CliError::InvalidArgument {
+                message: format!(
+                    "More than one device matches `{}`: {}",
+                    name,
+                    matches
+                        .iter()
+                        .map(|(device_name, _)| device_name.as_str())
+                        .collect::<Vec<&str>>()
+                        .join(", ")
+                ),
+            }
+            .into()),
         }
+    }
 
-        Ok(())
+    fn get_device_names_string(&self) -> String {
+        let mut names: Vec<&str> = self
+            .available_devices
+            .keys()
+            .map(|name| name.as_str())
+            .collect();
+
+        names.sort();
+
+        match names.is_empty() {
+            true => "none".to_string(),
+            false => names.join(", "),
+        }
+    }
+
+    pub async fn get_devices(
+        &self,
+        spotify_client: &mut SpotifyClient,
+    ) -> AppResult<Vec<DeviceObject>> {
+        let response = spotify_client
+            .get_json::<DevicesResponse>("me/player/devices")
+            .await?;
+
+        Ok(response.devices)
+    }
+
+    pub fn get_devices_string(devices: &[DeviceObject]) -> String {
+        let mut devices_string = String::new();
+
+        for device in devices {
+            if let Some(id) = &device.id {
+                devices_string = devices_string + &format!("id: {}\n", id);
+            }
+
+            devices_string = devices_string + &format!("name: {}\n\n", device.name);
+        }
+
+        devices_string.trim_end().to_string()
     }
 }
@@ -0,0 +1,96 @@
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpotifyError {
+    NoActiveDevice,
+    PremiumRequired,
+    MissingScope {
+        message: String,
+    },
+    RateLimited {
+        retry_after: Option<u64>,
+    },
+    Network {
+        message: String,
+    },
+    NotFound {
+        message: String,
+    },
+    AuthRevoked,
+    Api {
+        status: u16,
+        message: String,
+        reason: Option<String>,
+    },
+    InvalidResponse {
+        endpoint: String,
+        message: String,
+    },
+}
+
+impl SpotifyError {
+    pub fn from_status(status: u16, message: String, reason: Option<String>) -> Self {
+        match (status, reason.as_deref()) {
+            (404, Some("NO_ACTIVE_DEVICE")) => SpotifyError::NoActiveDevice,
+            (403, Some("PREMIUM_REQUIRED")) => SpotifyError::PremiumRequired,
+            (401 | 403, _) if message.to_lowercase().contains("scope") => {
+                SpotifyError::MissingScope { message }
+            }
+            (404, _) => SpotifyError::NotFound { message },
+            _ => SpotifyError::Api {
+                status,
+                message,
+                reason,
+            },
+        }
+    }
+}
+
+impl Display for SpotifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpotifyError::NoActiveDevice => write!(
+                f,
+                "No active Spotify device. Open Spotify on one of your devices or pick a device to play on."
+            ),
+            SpotifyError::PremiumRequired => {
+                write!(f, "This action requires a Spotify Premium account.")
+            }
+            SpotifyError::MissingScope { message } => write!(
+                f,
+                "The app is missing a permission ({}). Add the required scope to your config and log in again.",
+                message
+            ),
+            SpotifyError::RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(
+                f,
+                "Spotify is rate limiting requests. Try again in {} seconds.",
+                retry_after
+            ),
+            SpotifyError::RateLimited { retry_after: None } => {
+                write!(f, "Spotify is rate limiting requests. Try again later.")
+            }
+            SpotifyError::Network { message } => {
+                write!(f, "Could not reach Spotify: {}", message)
+            }
+            SpotifyError::NotFound { message } => {
+                write!(f, "Spotify could not find what you asked for: {}", message)
+            }
+            SpotifyError::AuthRevoked => write!(
+                f,
+                "Your Spotify login has expired or was revoked. Please log in again."
+            ),
+            SpotifyError::Api {
+                status, message, ..
+            } => write!(f, "Spotify returned {}: {}", status, message),
+            SpotifyError::InvalidResponse { endpoint, message } => write!(
+                f,
+                "Spotify returned an unexpected response for `{}`: {}",
+                endpoint, message
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SpotifyError {}
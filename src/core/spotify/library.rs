@@ -0,0 +1,149 @@
+use crate::core::app::AppResult;
+
+use super::{
+    client::SpotifyClient,
+    models::{
+        library::{FollowedArtistsResponse, SavedAlbumObject, SavedTrackObject},
+        paging::PagingObject,
+        playlist::SimplifiedPlaylistObject,
+    },
+    NameAndId,
+};
+
+const LIBRARY_PAGE_LIMIT: u64 = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LibraryType {
+    SavedTracks,
+    SavedAlbums,
+    FollowedArtists,
+    Playlists,
+}
+
+impl LibraryType {
+    pub fn get_title(&self) -> &str {
+        match self {
+            LibraryType::SavedTracks => "Liked Songs",
+            LibraryType::SavedAlbums => "Saved Albums",
+            LibraryType::FollowedArtists => "Followed Artists",
+            LibraryType::Playlists => "Playlists",
+        }
+    }
+
+    fn get_route(&self) -> String {
+        match self {
+            LibraryType::SavedTracks => format!("me/tracks?limit={}", LIBRARY_PAGE_LIMIT),
+            LibraryType::SavedAlbums => format!("me/albums?limit={}", LIBRARY_PAGE_LIMIT),
+            LibraryType::FollowedArtists => {
+                format!("me/following?type=artist&limit={}", LIBRARY_PAGE_LIMIT)
+            }
+            LibraryType::Playlists => format!("me/playlists?limit={}", LIBRARY_PAGE_LIMIT),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Library {
+    pub library_type: LibraryType,
+    pub items: Vec<NameAndId>,
+    pub total: u64,
+    pub is_loaded: bool,
+    next: Option<String>,
+}
+
+impl Library {
+    pub fn new(library_type: LibraryType) -> Self {
+        Self {
+            library_type,
+            items: vec![],
+            total: 0,
+            is_loaded: false,
+            next: None,
+        }
+    }
+
+    pub async fn refresh(&mut self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
+        let route = self.library_type.get_route();
+
+        self.items = vec![];
+        self.add_page(spotify_client, &route).await?;
+        self.is_loaded = true;
+
+        Ok(())
+    }
+
+    pub async fn next_page(&mut self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
+        if let Some(next) = self.next.take() {
+            self.add_page(spotify_client, &next).await?;
+        }
+
+        Ok(())
+    }
+
+    pub fn has_next_page(&self) -> bool {
+        self.next.is_some()
+    }
+
+    async fn add_page(&mut self, spotify_client: &mut SpotifyClient, route: &str) -> AppResult<()> {
+        match self.library_type {
+            LibraryType::SavedTracks => {
+                let page = spotify_client
+                    .get_json::<PagingObject<SavedTrackObject>>(route)
+                    .await?;
+
+                self.next = page.next;
+                self.total = page.total;
+                self.items.extend(page.items.into_iter().map(|saved| {
+                    (
+                        saved.track.track.get_display_name(),
+                        saved.track.track.id.unwrap_or_default(),
+                    )
+                }));
+            }
+            LibraryType::SavedAlbums => {
+                let page = spotify_client
+                    .get_json::<PagingObject<SavedAlbumObject>>(route)
+                    .await?;
+
+                self.next = page.next;
+                self.total = page.total;
+                self.items.extend(page.items.into_iter().map(|saved| {
+                    (
+                        saved.album.album.get_display_name(),
+                        saved.album.album.id.unwrap_or_default(),
+                    )
+                }));
+            }
+            LibraryType::FollowedArtists => {
+                let response = spotify_client
+                    .get_json::<FollowedArtistsResponse>(route)
+                    .await?;
+                let page = response.artists;
+
+                self.next = page.next;
+                self.total = page.total.unwrap_or_default();
+                self.items.extend(
+                    page.items
+                        .into_iter()
+                        .map(|artist| (artist.artist.name, artist.artist.id.unwrap_or_default())),
+                );
+            }
+            LibraryType::Playlists => {
+                let page = spotify_client
+                    .get_json::<PagingObject<Option<SimplifiedPlaylistObject>>>(route)
+                    .await?;
+
+                self.next = page.next;
+                self.total = page.total;
+                self.items.extend(
+                    page.items
+                        .into_iter()
+                        .flatten()
+                        .map(|playlist| (playlist.get_display_name(), playlist.id)),
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
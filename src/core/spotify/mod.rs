@@ -1,9 +1,21 @@
 pub mod album;
 pub mod artist;
+pub mod cache;
 pub mod client;
 pub mod device;
+pub mod error;
+pub mod library;
+pub mod models;
 pub mod now_playing;
+pub mod pager;
 pub mod player;
+pub mod playlist;
+pub mod poller;
+pub mod queue;
+pub mod request_stats;
+pub mod search;
 pub mod song;
+pub mod status;
+pub mod uri;
 
 pub type NameAndId = (String, String);
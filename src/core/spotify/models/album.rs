@@ -0,0 +1,50 @@
+use serde::Deserialize;
+
+use super::{
+    artist::SimplifiedArtistObject,
+    common::{ExternalUrlsObject, ImageObject},
+    paging::PagingObject,
+    track::SimplifiedTrackObject,
+};
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SimplifiedAlbumObject {
+    pub id: Option<String>,
+    pub name: String,
+    pub uri: Option<String>,
+    pub album_type: Option<String>,
+    pub album_group: Option<String>,
+    pub release_date: Option<String>,
+    #[serde(default)]
+    pub total_tracks: u64,
+    #[serde(default)]
+    pub artists: Vec<SimplifiedArtistObject>,
+    #[serde(default)]
+    pub images: Vec<ImageObject>,
+    #[serde(default)]
+    pub external_urls: ExternalUrlsObject,
+}
+
+impl SimplifiedAlbumObject {
+    pub fn get_display_name(&self) -> String {
+        let artists: Vec<&str> = self
+            .artists
+            .iter()
+            .map(|artist| artist.name.as_str())
+            .collect();
+
+        format!("{} - {}", self.name, artists.join(", "))
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AlbumObject {
+    #[serde(flatten)]
+    pub album: SimplifiedAlbumObject,
+    pub tracks: PagingObject<SimplifiedTrackObject>,
+    #[serde(default)]
+    pub genres: Vec<String>,
+    pub label: Option<String>,
+    #[serde(default)]
+    pub popularity: u64,
+}
@@ -0,0 +1,34 @@
+use serde::Deserialize;
+
+use super::{
+    common::{ExternalUrlsObject, FollowersObject, ImageObject},
+    track::TrackObject,
+};
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SimplifiedArtistObject {
+    pub id: Option<String>,
+    pub name: String,
+    pub uri: Option<String>,
+    #[serde(default)]
+    pub external_urls: ExternalUrlsObject,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ArtistObject {
+    #[serde(flatten)]
+    pub artist: SimplifiedArtistObject,
+    #[serde(default)]
+    pub genres: Vec<String>,
+    #[serde(default)]
+    pub followers: FollowersObject,
+    #[serde(default)]
+    pub popularity: u64,
+    #[serde(default)]
+    pub images: Vec<ImageObject>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TopTracksResponse {
+    pub tracks: Vec<TrackObject>,
+}
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct ImageObject {
+    pub url: String,
+    pub height: Option<u64>,
+    pub width: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct ExternalUrlsObject {
+    pub spotify: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct FollowersObject {
+    pub total: u64,
+}
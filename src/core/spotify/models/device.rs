@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DeviceObject {
+    pub id: Option<String>,
+    pub name: String,
+    #[serde(rename = "type", default)]
+    pub device_type: String,
+    #[serde(default)]
+    pub is_active: bool,
+    #[serde(default)]
+    pub is_private_session: bool,
+    #[serde(default)]
+    pub is_restricted: bool,
+    pub volume_percent: Option<u64>,
+    #[serde(default)]
+    pub supports_volume: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct DevicesResponse {
+    pub devices: Vec<DeviceObject>,
+}
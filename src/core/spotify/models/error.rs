@@ -0,0 +1,13 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ErrorResponse {
+    pub error: ErrorObject,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ErrorObject {
+    pub status: u16,
+    pub message: String,
+    pub reason: Option<String>,
+}
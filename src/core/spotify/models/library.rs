@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+use super::{
+    album::AlbumObject, artist::ArtistObject, paging::CursorPagingObject, track::TrackObject,
+};
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SavedTrackObject {
+    pub added_at: Option<String>,
+    pub track: TrackObject,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SavedAlbumObject {
+    pub added_at: Option<String>,
+    pub album: AlbumObject,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct FollowedArtistsResponse {
+    pub artists: CursorPagingObject<ArtistObject>,
+}
@@ -0,0 +1,12 @@
+pub mod album;
+pub mod artist;
+pub mod common;
+pub mod device;
+pub mod error;
+pub mod library;
+pub mod paging;
+pub mod playback;
+pub mod playlist;
+pub mod queue;
+pub mod search;
+pub mod track;
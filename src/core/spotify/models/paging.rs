@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PagingObject<T> {
+    pub href: Option<String>,
+    pub items: Vec<T>,
+    pub limit: u64,
+    pub next: Option<String>,
+    pub offset: u64,
+    pub previous: Option<String>,
+    pub total: u64,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct CursorObject {
+    pub after: Option<String>,
+    pub before: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CursorPagingObject<T> {
+    pub href: Option<String>,
+    pub items: Vec<T>,
+    pub limit: u64,
+    pub next: Option<String>,
+    #[serde(default)]
+    pub cursors: CursorObject,
+    pub total: Option<u64>,
+}
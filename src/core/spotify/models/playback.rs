@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+use super::{device::DeviceObject, track::PlayableItem};
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ContextObject {
+    #[serde(rename = "type")]
+    pub context_type: String,
+    pub uri: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PlaybackStateObject {
+    pub device: Option<DeviceObject>,
+    pub repeat_state: String,
+    pub shuffle_state: bool,
+    pub context: Option<ContextObject>,
+    pub timestamp: u64,
+    pub progress_ms: Option<u64>,
+    pub is_playing: bool,
+    pub item: Option<PlayableItem>,
+}
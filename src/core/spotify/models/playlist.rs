@@ -0,0 +1,90 @@
+use serde::Deserialize;
+
+use super::{
+    common::{ExternalUrlsObject, FollowersObject, ImageObject},
+    paging::PagingObject,
+    track::PlayableItem,
+};
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct PublicUserObject {
+    pub id: String,
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub external_urls: ExternalUrlsObject,
+}
+
+impl PublicUserObject {
+    pub fn get_name(&self) -> String {
+        match &self.display_name {
+            Some(display_name) => display_name.clone(),
+            None => self.id.clone(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct PlaylistTracksRefObject {
+    pub href: Option<String>,
+    #[serde(default)]
+    pub total: u64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SimplifiedPlaylistObject {
+    pub id: String,
+    pub name: String,
+    pub uri: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub owner: PublicUserObject,
+    pub public: Option<bool>,
+    #[serde(default)]
+    pub collaborative: bool,
+    pub snapshot_id: Option<String>,
+    #[serde(default)]
+    pub tracks: PlaylistTracksRefObject,
+    pub images: Option<Vec<ImageObject>>,
+    #[serde(default)]
+    pub external_urls: ExternalUrlsObject,
+}
+
+impl SimplifiedPlaylistObject {
+    pub fn get_display_name(&self) -> String {
+        format!("{} - {}", self.name, self.owner.get_name())
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SnapshotResponse {
+    pub snapshot_id: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PlaylistTrackObject {
+    pub added_at: Option<String>,
+    pub added_by: Option<PublicUserObject>,
+    #[serde(default)]
+    pub is_local: bool,
+    pub track: Option<PlayableItem>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PlaylistObject {
+    pub id: String,
+    pub name: String,
+    pub uri: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub owner: PublicUserObject,
+    pub public: Option<bool>,
+    #[serde(default)]
+    pub collaborative: bool,
+    pub snapshot_id: Option<String>,
+    #[serde(default)]
+    pub followers: FollowersObject,
+    pub tracks: PagingObject<PlaylistTrackObject>,
+    pub images: Option<Vec<ImageObject>>,
+    #[serde(default)]
+    pub external_urls: ExternalUrlsObject,
+}
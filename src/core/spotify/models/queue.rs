@@ -0,0 +1,10 @@
+use serde::Deserialize;
+
+use super::track::PlayableItem;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct QueueResponse {
+    pub currently_playing: Option<PlayableItem>,
+    #[serde(default)]
+    pub queue: Vec<PlayableItem>,
+}
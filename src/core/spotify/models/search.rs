@@ -0,0 +1,14 @@
+use serde::Deserialize;
+
+use super::{
+    album::SimplifiedAlbumObject, artist::ArtistObject, paging::PagingObject,
+    playlist::SimplifiedPlaylistObject, track::TrackObject,
+};
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SearchResponse {
+    pub tracks: Option<PagingObject<TrackObject>>,
+    pub albums: Option<PagingObject<SimplifiedAlbumObject>>,
+    pub artists: Option<PagingObject<ArtistObject>>,
+    pub playlists: Option<PagingObject<Option<SimplifiedPlaylistObject>>>,
+}
@@ -0,0 +1,64 @@
+use serde::Deserialize;
+
+use super::{
+    album::SimplifiedAlbumObject, artist::SimplifiedArtistObject, common::ExternalUrlsObject,
+};
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SimplifiedTrackObject {
+    pub id: Option<String>,
+    pub name: String,
+    pub uri: Option<String>,
+    #[serde(default)]
+    pub artists: Vec<SimplifiedArtistObject>,
+    #[serde(default)]
+    pub duration_ms: u64,
+    #[serde(default)]
+    pub disc_number: u64,
+    #[serde(default)]
+    pub track_number: u64,
+    #[serde(default)]
+    pub explicit: bool,
+    #[serde(default)]
+    pub is_local: bool,
+    pub is_playable: Option<bool>,
+    #[serde(default)]
+    pub external_urls: ExternalUrlsObject,
+}
+
+impl SimplifiedTrackObject {
+    pub fn get_display_name(&self) -> String {
+        let artists: Vec<&str> = self
+            .artists
+            .iter()
+            .map(|artist| artist.name.as_str())
+            .collect();
+
+        format!("{} - {}", self.name, artists.join(", "))
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct TrackObject {
+    #[serde(flatten)]
+    pub track: SimplifiedTrackObject,
+    pub album: SimplifiedAlbumObject,
+    #[serde(default)]
+    pub popularity: u64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct EpisodeObject {
+    pub id: Option<String>,
+    pub name: String,
+    pub uri: Option<String>,
+    #[serde(default)]
+    pub duration_ms: u64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum PlayableItem {
+    Track(Box<TrackObject>),
+    Episode(EpisodeObject),
+}
@@ -1,34 +1,78 @@
-use color_eyre::eyre::eyre;
-use serde_json::Value;
+use std::time::{Duration, Instant};
 
-use crate::{core::app::AppResult, utils::value::GetOrDefault};
+use crate::{
+    core::app::AppResult,
+    utils::time::{get_unix_time_millis, milliseconds_to_string},
+};
 
-use super::client::SpotifyClient;
+use super::{
+    client::SpotifyClient,
+    error::SpotifyError,
+    models::{playback::PlaybackStateObject, track::PlayableItem},
+};
 
-#[derive(Debug, Clone)]
+const PROGRESS_DRIFT_TOLERANCE_MS: u64 = 1_500;
+const MAX_TIMESTAMP_AGE_MS: u64 = 1_000;
+
+#[derive(Debug, Clone, Default)]
 pub struct NowPlaying {
     pub song_id: String,
     pub album_id: String,
     pub artist_ids: Vec<String>,
     pub progress: u64,
+    pub duration: u64,
     pub shuffle: bool,
+    pub is_playing: bool,
+    pub repeat_state: String,
+    pub context_uri: Option<String>,
+    pub device_id: Option<String>,
+    pub device_name: Option<String>,
+    pub volume: Option<u64>,
+    pub timestamp: u64,
+    pub synced_at: Option<Instant>,
 }
 
-impl Default for NowPlaying {
-    fn default() -> Self {
-        Self {
-            song_id: String::new(),
-            album_id: String::new(),
-            artist_ids: Vec::<String>::new(),
-            progress: 0,
-            shuffle: false,
+impl NowPlaying {
+    pub fn get_progress(&self) -> u64 {
+        match (self.is_playing, self.synced_at) {
+            (true, Some(synced_at)) => {
+                let progress = self.progress + synced_at.elapsed().as_millis() as u64;
+
+                match self.duration {
+                    0 => progress,
+                    duration => progress.min(duration),
+                }
+            }
+            _ => self.progress,
+        }
+    }
+
+    pub fn set_progress(&mut self, progress: u64) {
+        self.progress = progress;
+        self.synced_at = Some(Instant::now());
+    }
+
+    pub fn sync(&mut self, now_playing: &NowPlaying) {
+        let is_in_sync = self.song_id == now_playing.song_id
+            && self.is_playing == now_playing.is_playing
+            && self.timestamp == now_playing.timestamp
+            && self.synced_at.is_some()
+            && self.get_progress().abs_diff(now_playing.get_progress())
+                < PROGRESS_DRIFT_TOLERANCE_MS;
+
+        let progress = self.progress;
+        let synced_at = self.synced_at;
+
+        *self = now_playing.clone();
+
+        if is_in_sync {
+            self.progress = progress;
+            self.synced_at = synced_at;
         }
     }
-}
 
-impl NowPlaying {
     pub fn get_progress_string(&self) -> String {
-        Self::milliseconds_to_string(self.progress)
+        milliseconds_to_string(self.get_progress())
     }
 
     pub fn get_shuffle_string(&self) -> String {
@@ -38,59 +82,114 @@ impl NowPlaying {
         }
     }
 
+    pub fn get_repeat_string(&self) -> String {
+        match self.repeat_state.as_str() {
+            "context" => "Repeat: All".to_string(),
+            "track" => "Repeat: Song".to_string(),
+            _ => "Repeat: Off".to_string(),
+        }
+    }
+
+    pub fn get_volume_string(&self) -> String {
+        match self.volume {
+            Some(volume) => format!("Volume: {}%", volume),
+            None => "Volume: -".to_string(),
+        }
+    }
+
+    pub fn nudge_progress(&mut self, offset_ms: i64, song_length: u64) {
+        let progress = (self.get_progress() as i64 + offset_ms).clamp(0, song_length as i64);
+
+        self.set_progress(progress as u64);
+    }
+
     pub async fn refresh(&mut self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
         let response = spotify_client.get("me/player").await?;
         let status = response.status();
 
         if status == 204 {
-            return Err(eyre!("No device available"));
+            return Err(SpotifyError::NoActiveDevice.into());
         }
 
-        let json = response.json::<Value>().await?;
+        let playback_state =
+            SpotifyClient::parse_json::<PlaybackStateObject>("me/player", response).await?;
 
         let mut song_id = String::new();
         let mut album_id = String::new();
         let mut artist_ids = Vec::<String>::new();
-        let progress = json.get_number_or_default("progress_ms");
-        let shuffle = json.get_bool_or_default("shuffle_state");
-
-        if let Some(item) = json.get("item") {
-            song_id = item.get_string_or_default("id");
-
-            if let Some(album_value) = item.get("album") {
-                album_id = album_value.get_string_or_default("id");
-            }
-
-            let artists = item.get_array_or_default("artists");
-
-            for artist in artists {
-                let id = artist.get_string_or_default("id");
-
-                artist_ids.push(id);
+        let mut duration = 0;
+
+        match playback_state.item {
+            Some(PlayableItem::Track(track)) => {
+                song_id = track.track.id.unwrap_or_default();
+                album_id = track.album.id.unwrap_or_default();
+                artist_ids = track
+                    .track
+                    .artists
+                    .into_iter()
+                    .filter_map(|artist| artist.id)
+                    .collect();
+                duration = track.track.duration_ms;
             }
+            Some(PlayableItem::Episode(episode)) => duration = episode.duration_ms,
+            None => {}
         }
 
         self.song_id = song_id;
         self.album_id = album_id;
         self.artist_ids = artist_ids;
-        self.progress = progress;
-        self.shuffle = shuffle;
+        self.progress = playback_state.progress_ms.unwrap_or_default();
+        self.synced_at = Some(Self::get_synced_at(playback_state.timestamp));
+        self.duration = duration;
+        self.shuffle = playback_state.shuffle_state;
+        self.is_playing = playback_state.is_playing;
+        self.repeat_state = playback_state.repeat_state;
+        self.context_uri = playback_state.context.map(|context| context.uri);
+        self.device_id = playback_state
+            .device
+            .as_ref()
+            .and_then(|device| device.id.clone());
+        self.device_name = playback_state
+            .device
+            .as_ref()
+            .map(|device| device.name.clone());
+        self.volume = playback_state
+            .device
+            .and_then(|device| device.volume_percent);
+        self.timestamp = playback_state.timestamp;
 
         Ok(())
     }
 
     pub fn is_empty(&self) -> bool {
-        self.song_id == "".to_string()
-            || self.album_id == "".to_string()
-            || self.artist_ids.is_empty()
-            || self.progress == 0
+        self.song_id.is_empty()
     }
 
-    fn milliseconds_to_string(ms: u64) -> String {
-        let total_seconds = ms / 1_000;
-        let minutes = total_seconds / 60;
-        let seconds = total_seconds % 60;
+    fn get_synced_at(timestamp: u64) -> Instant {
+        let now = Instant::now();
+        let age = get_unix_time_millis().saturating_sub(timestamp);
+
+        match age <= MAX_TIMESTAMP_AGE_MS {
+            true => now.checked_sub(Duration::from_millis(age)).unwrap_or(now),
+            false => now,
+        }
+    }
+}
 
-        format!("{}:{:02}", minutes, seconds)
+impl PartialEq for NowPlaying {
+    fn eq(&self, other: &Self) -> bool {
+        self.song_id == other.song_id
+            && self.album_id == other.album_id
+            && self.artist_ids == other.artist_ids
+            && self.progress == other.progress
+            && self.duration == other.duration
+            && self.shuffle == other.shuffle
+            && self.is_playing == other.is_playing
+            && self.repeat_state == other.repeat_state
+            && self.context_uri == other.context_uri
+            && self.device_id == other.device_id
+            && self.device_name == other.device_name
+            && self.volume == other.volume
+            && self.timestamp == other.timestamp
     }
 }
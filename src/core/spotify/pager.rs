@@ -0,0 +1,112 @@
+use serde::de::DeserializeOwned;
+
+use crate::core::app::AppResult;
+
+use super::{client::SpotifyClient, models::paging::PagingObject};
+
+#[derive(Debug, Clone)]
+pub struct Pager<T> {
+    next: Option<String>,
+    first_page: Option<Vec<T>>,
+    total: Option<u64>,
+    items_fetched: usize,
+    max_items: Option<usize>,
+    is_cached: bool,
+}
+
+impl<T: DeserializeOwned> Pager<T> {
+    pub fn new(route: impl Into<String>) -> Self {
+        Self {
+            next: Some(route.into()),
+            first_page: None,
+            total: None,
+            items_fetched: 0,
+            max_items: None,
+            is_cached: false,
+        }
+    }
+
+    pub fn from_page(page: PagingObject<T>) -> Self {
+        Self {
+            next: page.next,
+            first_page: Some(page.items),
+            total: Some(page.total),
+            items_fetched: 0,
+            max_items: None,
+            is_cached: false,
+        }
+    }
+
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    pub fn with_cache(mut self) -> Self {
+        self.is_cached = true;
+        self
+    }
+
+    pub fn get_total(&self) -> Option<u64> {
+        self.total
+    }
+
+    pub fn has_next_page(&self) -> bool {
+        let is_capped = match self.max_items {
+            Some(max_items) => self.items_fetched >= max_items,
+            None => false,
+        };
+
+        !is_capped && (self.first_page.is_some() || self.next.is_some())
+    }
+
+    pub async fn next_page(
+        &mut self,
+        spotify_client: &mut SpotifyClient,
+    ) -> AppResult<Option<Vec<T>>> {
+        if !self.has_next_page() {
+            return Ok(None);
+        }
+
+        let mut items = match self.first_page.take() {
+            Some(items) => items,
+            None => {
+                let route = match self.next.take() {
+                    Some(route) => route,
+                    None => return Ok(None),
+                };
+                let page = match self.is_cached {
+                    true => {
+                        spotify_client
+                            .get_cached_json::<PagingObject<T>>(&route)
+                            .await?
+                    }
+                    false => spotify_client.get_json::<PagingObject<T>>(&route).await?,
+                };
+
+                self.next = page.next;
+                self.total = Some(page.total);
+
+                page.items
+            }
+        };
+
+        if let Some(max_items) = self.max_items {
+            items.truncate(max_items.saturating_sub(self.items_fetched));
+        }
+
+        self.items_fetched += items.len();
+
+        Ok(Some(items))
+    }
+
+    pub async fn collect_all(mut self, spotify_client: &mut SpotifyClient) -> AppResult<Vec<T>> {
+        let mut items = vec![];
+
+        while let Some(page) = self.next_page(spotify_client).await? {
+            items.extend(page);
+        }
+
+        Ok(items)
+    }
+}
@@ -1,11 +1,16 @@
-use color_eyre::eyre::eyre;
-use log::error;
-use serde_json::{json, Value};
+use serde_json::json;
 
 use crate::core::app::AppResult;
 use crate::core::spotify::NameAndId;
 
-use super::client::SpotifyClient;
+use super::{
+    client::SpotifyClient,
+    error::SpotifyError,
+    models::{playback::PlaybackStateObject, track::PlayableItem},
+    uri::SpotifyUri,
+};
+
+pub const REPEAT_STATES: [&str; 3] = ["off", "context", "track"];
 
 pub struct SpotifyPlayer;
 
@@ -35,6 +40,26 @@ impl SpotifyPlayer {
         Ok(())
     }
 
+    pub async fn play_song_on_playlist(
+        &self,
+        spotify_client: &mut SpotifyClient,
+        position: usize,
+        playlist_id: String,
+    ) -> AppResult<()> {
+        let playlist_uri = format!("spotify:playlist:{}", playlist_id);
+
+        let body = json!({
+            "context_uri": playlist_uri,
+            "offset": {
+                "position": position,
+            }
+        });
+
+        spotify_client.put("me/player/play", Some(&body)).await?;
+
+        Ok(())
+    }
+
     pub async fn play_songs(
         &self,
         spotify_client: &mut SpotifyClient,
@@ -56,6 +81,60 @@ impl SpotifyPlayer {
         Ok(())
     }
 
+    pub async fn play_context(
+        &self,
+        spotify_client: &mut SpotifyClient,
+        context_uri: String,
+    ) -> AppResult<()> {
+        let body = json!({
+            "context_uri": context_uri,
+        });
+
+        spotify_client.put("me/player/play", Some(&body)).await?;
+
+        Ok(())
+    }
+
+    pub async fn play_uri(
+        &self,
+        spotify_client: &mut SpotifyClient,
+        uri: &SpotifyUri,
+    ) -> AppResult<()> {
+        let body = match uri.is_playable_item() {
+            true => json!({ "uris": [uri.to_string()] }),
+            false => json!({ "context_uri": uri.to_string() }),
+        };
+
+        spotify_client.put("me/player/play", Some(&body)).await?;
+
+        Ok(())
+    }
+
+    pub async fn add_to_queue(
+        &self,
+        spotify_client: &mut SpotifyClient,
+        song_id: String,
+    ) -> AppResult<()> {
+        let uri = SpotifyUri {
+            uri_type: "track".to_string(),
+            id: song_id,
+        };
+
+        self.add_uri_to_queue(spotify_client, &uri).await
+    }
+
+    pub async fn add_uri_to_queue(
+        &self,
+        spotify_client: &mut SpotifyClient,
+        uri: &SpotifyUri,
+    ) -> AppResult<()> {
+        let url = format!("me/player/queue?uri={}", uri);
+
+        spotify_client.post(&url, None).await?;
+
+        Ok(())
+    }
+
     pub async fn toggle_pause_play(&self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
         if self.is_playing(spotify_client).await? {
             spotify_client.put("me/player/pause", None).await?;
@@ -71,23 +150,13 @@ impl SpotifyPlayer {
         let status = response.status();
 
         if status == 204 {
-            let error_message = "No Spotify device active.";
-
-            error!("{}", error_message);
-            return Err(eyre!(error_message));
+            return Err(SpotifyError::NoActiveDevice.into());
         }
 
-        if status == 200 {
-            let response_json = response.json::<Value>().await?;
+        let playback_state =
+            SpotifyClient::parse_json::<PlaybackStateObject>("me/player", response).await?;
 
-            if let Some(is_playing) = response_json.get("is_playing") {
-                if let Value::Bool(is_playing) = is_playing {
-                    return Ok(is_playing.to_owned());
-                }
-            }
-        }
-
-        Ok(false)
+        Ok(playback_state.is_playing)
     }
 
     pub async fn next_song(&self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
@@ -106,19 +175,114 @@ impl SpotifyPlayer {
         let response = spotify_client.get("me/player").await?;
         let status = response.status();
 
-        if status == 200 {
-            let response_json = response.json::<Value>().await?;
+        if status == 204 {
+            return Err(SpotifyError::NoActiveDevice.into());
+        }
 
-            if let Some(current_shuffle_state) = response_json.get("shuffle_state") {
-                if let Value::Bool(current_shuffle_state) = current_shuffle_state {
-                    let shuffle_state = !current_shuffle_state;
-                    let url = format!("me/player/shuffle?state={}", shuffle_state.to_string());
+        if status == 200 {
+            let playback_state =
+                SpotifyClient::parse_json::<PlaybackStateObject>("me/player", response).await?;
+            let shuffle_state = !playback_state.shuffle_state;
+            let url = format!("me/player/shuffle?state={}", shuffle_state);
 
-                    spotify_client.put(&url, None).await?;
-                }
-            }
+            spotify_client.put(&url, None).await?;
         }
 
         Ok(())
     }
+
+    pub async fn seek(
+        &self,
+        spotify_client: &mut SpotifyClient,
+        position_ms: u64,
+    ) -> AppResult<()> {
+        let url = format!("me/player/seek?position_ms={}", position_ms);
+
+        spotify_client.put(&url, None).await?;
+
+        Ok(())
+    }
+
+    pub async fn seek_relative(
+        &self,
+        spotify_client: &mut SpotifyClient,
+        offset_ms: i64,
+    ) -> AppResult<()> {
+        let playback_state = self.get_playback_state(spotify_client).await?;
+        let duration_ms = match playback_state.item {
+            Some(PlayableItem::Track(track)) => track.track.duration_ms,
+            Some(PlayableItem::Episode(episode)) => episode.duration_ms,
+            None => return Err(SpotifyError::NoActiveDevice.into()),
+        };
+        let progress_ms = playback_state.progress_ms.unwrap_or_default() as i64;
+        let position_ms = (progress_ms + offset_ms).clamp(0, duration_ms as i64);
+
+        self.seek(spotify_client, position_ms as u64).await
+    }
+
+    pub async fn set_volume(
+        &self,
+        spotify_client: &mut SpotifyClient,
+        volume_percent: u64,
+    ) -> AppResult<()> {
+        let url = format!(
+            "me/player/volume?volume_percent={}",
+            volume_percent.min(100)
+        );
+
+        spotify_client.put(&url, None).await?;
+
+        Ok(())
+    }
+
+    pub async fn step_volume(
+        &self,
+        spotify_client: &mut SpotifyClient,
+        step: i64,
+    ) -> AppResult<()> {
+        let playback_state = self.get_playback_state(spotify_client).await?;
+        let volume_percent = playback_state
+            .device
+            .and_then(|device| device.volume_percent)
+            .unwrap_or_default() as i64;
+
+        self.set_volume(spotify_client, (volume_percent + step).clamp(0, 100) as u64)
+            .await
+    }
+
+    pub async fn set_repeat(
+        &self,
+        spotify_client: &mut SpotifyClient,
+        repeat_state: &str,
+    ) -> AppResult<()> {
+        let url = format!("me/player/repeat?state={}", repeat_state);
+
+        spotify_client.put(&url, None).await?;
+
+        Ok(())
+    }
+
+    pub async fn cycle_repeat(&self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
+        let playback_state = self.get_playback_state(spotify_client).await?;
+        let index = REPEAT_STATES
+            .iter()
+            .position(|state| *state == playback_state.repeat_state)
+            .unwrap_or_default();
+        let repeat_state = REPEAT_STATES[(index + 1) % REPEAT_STATES.len()];
+
+        self.set_repeat(spotify_client, repeat_state).await
+    }
+
+    async fn get_playback_state(
+        &self,
+        spotify_client: &mut SpotifyClient,
+    ) -> AppResult<PlaybackStateObject> {
+        let response = spotify_client.get("me/player").await?;
+
+        if response.status() == 204 {
+            return Err(SpotifyError::NoActiveDevice.into());
+        }
+
+        SpotifyClient::parse_json::<PlaybackStateObject>("me/player", response).await
+    }
 }
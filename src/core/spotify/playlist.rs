@@ -0,0 +1,278 @@
+use serde_json::json;
+
+use crate::core::app::AppResult;
+
+use super::{
+    client::SpotifyClient,
+    models::{
+        playlist::{PlaylistObject, PlaylistTrackObject, PublicUserObject, SnapshotResponse},
+        track::PlayableItem,
+    },
+    pager::Pager,
+    NameAndId,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaylistItem {
+    pub name: String,
+    pub id: String,
+    pub uri: String,
+    pub is_local: bool,
+    pub is_playable: bool,
+}
+
+impl From<PlaylistTrackObject> for PlaylistItem {
+    fn from(item: PlaylistTrackObject) -> Self {
+        match item.track {
+            Some(PlayableItem::Track(track)) => {
+                let is_local = item.is_local || track.track.is_local;
+                let id = track.track.id.clone().unwrap_or_default();
+
+                Self {
+                    name: track.track.get_display_name(),
+                    uri: track.track.uri.clone().unwrap_or_default(),
+                    is_playable: !is_local
+                        && !id.is_empty()
+                        && track.track.is_playable != Some(false),
+                    is_local,
+                    id,
+                }
+            }
+            Some(PlayableItem::Episode(episode)) => Self {
+                name: episode.name,
+                id: episode.id.unwrap_or_default(),
+                uri: episode.uri.unwrap_or_default(),
+                is_local: false,
+                is_playable: true,
+            },
+            None => Self {
+                name: "Unknown song".to_string(),
+                id: String::new(),
+                uri: String::new(),
+                is_local: item.is_local,
+                is_playable: false,
+            },
+        }
+    }
+}
+
+impl PlaylistItem {
+    pub fn get_display_name(&self) -> String {
+        match (self.is_local, self.is_playable) {
+            (true, _) => format!("{} (local)", self.name),
+            (false, false) => format!("{} (unavailable)", self.name),
+            (false, true) => self.name.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Playlist {
+    pub id: String,
+    pub uri: String,
+    pub name: String,
+    pub description: String,
+    pub owner_id: String,
+    pub owner_name: String,
+    pub followers: u64,
+    pub public: bool,
+    pub collaborative: bool,
+    pub snapshot_id: String,
+    pub items: Vec<PlaylistItem>,
+    pub total_songs: u64,
+    pub is_loaded: bool,
+}
+
+impl From<PlaylistObject> for Playlist {
+    fn from(playlist: PlaylistObject) -> Self {
+        Self {
+            id: playlist.id,
+            uri: playlist.uri,
+            name: playlist.name,
+            description: playlist.description.unwrap_or_default(),
+            owner_name: playlist.owner.get_name(),
+            owner_id: playlist.owner.id,
+            followers: playlist.followers.total,
+            public: playlist.public.unwrap_or_default(),
+            collaborative: playlist.collaborative,
+            snapshot_id: playlist.snapshot_id.unwrap_or_default(),
+            total_songs: playlist.tracks.total,
+            items: playlist
+                .tracks
+                .items
+                .into_iter()
+                .map(PlaylistItem::from)
+                .collect(),
+            is_loaded: true,
+        }
+    }
+}
+
+impl Playlist {
+    pub fn new(id: String) -> Self {
+        Self {
+            id,
+            ..Self::default()
+        }
+    }
+
+    pub async fn refresh(&mut self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
+        let url = format!("playlists/{}", self.id);
+        let mut playlist = spotify_client.get_json::<PlaylistObject>(&url).await?;
+
+        playlist.tracks.items = Pager::from_page(playlist.tracks.clone())
+            .collect_all(spotify_client)
+            .await?;
+
+        *self = Playlist::from(playlist);
+
+        Ok(())
+    }
+
+    pub async fn create(
+        spotify_client: &mut SpotifyClient,
+        name: String,
+        description: String,
+        public: bool,
+    ) -> AppResult<Playlist> {
+        let user = spotify_client.get_json::<PublicUserObject>("me").await?;
+        let route = format!("users/{}/playlists", user.id);
+
+        let body = json!({
+            "name": name,
+            "description": description,
+            "public": public,
+        });
+
+        let response = spotify_client.post(&route, Some(&body)).await?;
+        let playlist = SpotifyClient::parse_json::<PlaylistObject>(&route, response).await?;
+
+        Ok(Playlist::from(playlist))
+    }
+
+    pub async fn update_details(
+        &mut self,
+        spotify_client: &mut SpotifyClient,
+        name: String,
+        description: String,
+        public: bool,
+    ) -> AppResult<()> {
+        let route = format!("playlists/{}", self.id);
+
+        let body = json!({
+            "name": name,
+            "description": description,
+            "public": public,
+        });
+
+        spotify_client.put(&route, Some(&body)).await?;
+
+        self.name = name;
+        self.description = description;
+        self.public = public;
+
+        Ok(())
+    }
+
+    pub async fn add_songs(
+        &mut self,
+        spotify_client: &mut SpotifyClient,
+        uris: Vec<String>,
+    ) -> AppResult<()> {
+        let route = format!("playlists/{}/tracks", self.id);
+
+        let body = json!({
+            "uris": uris,
+        });
+
+        let response = spotify_client.post(&route, Some(&body)).await?;
+        let snapshot = SpotifyClient::parse_json::<SnapshotResponse>(&route, response).await?;
+
+        self.snapshot_id = snapshot.snapshot_id;
+        self.is_loaded = false;
+
+        Ok(())
+    }
+
+    pub async fn remove_song(
+        &mut self,
+        spotify_client: &mut SpotifyClient,
+        position: usize,
+    ) -> AppResult<()> {
+        let uri = match self.items.get(position) {
+            Some(item) if !item.uri.is_empty() => item.uri.clone(),
+            _ => return Ok(()),
+        };
+        let route = format!("playlists/{}/tracks", self.id);
+
+        let body = json!({
+            "tracks": [{ "uri": uri }],
+            "snapshot_id": self.snapshot_id,
+        });
+
+        let response = spotify_client.delete(&route, Some(&body)).await?;
+        let snapshot = SpotifyClient::parse_json::<SnapshotResponse>(&route, response).await?;
+
+        self.snapshot_id = snapshot.snapshot_id;
+        self.items.retain(|item| item.uri != uri);
+        self.total_songs = self.items.len() as u64;
+
+        Ok(())
+    }
+
+    pub async fn move_song(
+        &mut self,
+        spotify_client: &mut SpotifyClient,
+        from: usize,
+        to: usize,
+    ) -> AppResult<()> {
+        if from == to || from >= self.items.len() || to >= self.items.len() {
+            return Ok(());
+        }
+
+        let route = format!("playlists/{}/tracks", self.id);
+        let insert_before = match to > from {
+            true => to + 1,
+            false => to,
+        };
+
+        let body = json!({
+            "range_start": from,
+            "insert_before": insert_before,
+            "range_length": 1,
+            "snapshot_id": self.snapshot_id,
+        });
+
+        let response = spotify_client.put(&route, Some(&body)).await?;
+        let snapshot = SpotifyClient::parse_json::<SnapshotResponse>(&route, response).await?;
+
+        let item = self.items.remove(from);
+
+        self.items.insert(to, item);
+        self.snapshot_id = snapshot.snapshot_id;
+
+        Ok(())
+    }
+
+    pub fn get_song_copies(&self, position: usize) -> usize {
+        match self.items.get(position) {
+            Some(song) if !song.uri.is_empty() => self
+                .items
+                .iter()
+                .filter(|item| item.uri == song.uri)
+                .count(),
+            _ => 0,
+        }
+    }
+
+    pub fn get_songs(&self) -> Vec<NameAndId> {
+        self.items
+            .iter()
+            .map(|item| (item.get_display_name(), item.id.clone()))
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.id.is_empty() || self.name.is_empty()
+    }
+}
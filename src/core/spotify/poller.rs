@@ -0,0 +1,147 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::{
+    sync::{watch, Notify},
+    task::JoinHandle,
+    time::timeout,
+};
+
+use super::{client::SpotifyClient, error::SpotifyError, now_playing::NowPlaying};
+
+const PLAYING_INTERVAL: Duration = Duration::from_secs(1);
+const PAUSED_INTERVAL: Duration = Duration::from_secs(3);
+const IDLE_INTERVAL: Duration = Duration::from_secs(5);
+const TRACK_END_MARGIN: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlaybackState {
+    pub now_playing: NowPlaying,
+    pub error: Option<SpotifyError>,
+}
+
+impl PlaybackState {
+    async fn fetch(spotify_client: &mut SpotifyClient) -> Self {
+        let mut now_playing = NowPlaying::default();
+
+        match now_playing.refresh(spotify_client).await {
+            Ok(_) => Self {
+                now_playing,
+                error: None,
+            },
+            Err(error) => {
+                let error = match error.downcast::<SpotifyError>() {
+                    Ok(spotify_error) => spotify_error,
+                    Err(error) => SpotifyError::InvalidResponse {
+                        endpoint: "me/player".to_string(),
+                        message: error.to_string(),
+                    },
+                };
+
+                Self {
+                    now_playing: NowPlaying::default(),
+                    error: Some(error),
+                }
+            }
+        }
+    }
+
+    fn get_poll_interval(&self) -> Duration {
+        if self.error.is_some() || self.now_playing.song_id.is_empty() {
+            return IDLE_INTERVAL;
+        }
+
+        if !self.now_playing.is_playing {
+            return PAUSED_INTERVAL;
+        }
+
+        let remaining = Duration::from_millis(
+            self.now_playing
+                .duration
+                .saturating_sub(self.now_playing.progress),
+        );
+
+        match remaining < PLAYING_INTERVAL {
+            true => remaining + TRACK_END_MARGIN,
+            false => PLAYING_INTERVAL,
+        }
+    }
+}
+
+pub struct PlaybackPoller {
+    receiver: watch::Receiver<PlaybackState>,
+    wake: Arc<Notify>,
+    handle: JoinHandle<()>,
+    error_shown: bool,
+}
+
+impl PlaybackPoller {
+    pub fn new(spotify_client: SpotifyClient) -> Self {
+        let (sender, receiver) = watch::channel(PlaybackState::default());
+        let wake = Arc::new(Notify::new());
+        let handle = tokio::spawn(Self::poll(spotify_client, sender, wake.clone()));
+
+        Self {
+            receiver,
+            wake,
+            handle,
+            error_shown: false,
+        }
+    }
+
+    pub fn refresh_now(&self) {
+        self.wake.notify_one();
+    }
+
+    pub fn get_state(&self) -> PlaybackState {
+        self.receiver.borrow().clone()
+    }
+
+    pub async fn changed(&mut self) -> Option<PlaybackState> {
+        self.receiver.changed().await.ok()?;
+        self.error_shown = false;
+
+        Some(self.receiver.borrow_and_update().clone())
+    }
+
+    pub fn take_error(&mut self) -> Option<SpotifyError> {
+        if self.error_shown || self.receiver.has_changed().unwrap_or(false) {
+            return None;
+        }
+
+        let error = self.receiver.borrow().error.clone()?;
+        self.error_shown = true;
+
+        Some(error)
+    }
+
+    async fn poll(
+        mut spotify_client: SpotifyClient,
+        sender: watch::Sender<PlaybackState>,
+        wake: Arc<Notify>,
+    ) {
+        loop {
+            let state = PlaybackState::fetch(&mut spotify_client).await;
+            let poll_interval = state.get_poll_interval();
+
+            sender.send_if_modified(|current| match *current == state {
+                true => false,
+                false => {
+                    *current = state;
+                    true
+                }
+            });
+
+            if sender.is_closed() {
+                return;
+            }
+
+            let _ = timeout(poll_interval, wake.notified()).await;
+        }
+    }
+}
+
+impl Drop for PlaybackPoller {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
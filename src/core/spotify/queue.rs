@@ -0,0 +1,38 @@
+use crate::core::app::AppResult;
+
+use super::{
+    client::SpotifyClient,
+    models::{queue::QueueResponse, track::PlayableItem},
+    NameAndId,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct Queue {
+    pub currently_playing: Option<NameAndId>,
+    pub songs: Vec<NameAndId>,
+    pub is_loaded: bool,
+}
+
+impl Queue {
+    pub async fn refresh(&mut self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
+        let queue = spotify_client
+            .get_json::<QueueResponse>("me/player/queue")
+            .await?;
+
+        self.currently_playing = queue.currently_playing.map(Self::get_name_and_id);
+        self.songs = queue.queue.into_iter().map(Self::get_name_and_id).collect();
+        self.is_loaded = true;
+
+        Ok(())
+    }
+
+    fn get_name_and_id(item: PlayableItem) -> NameAndId {
+        match item {
+            PlayableItem::Track(track) => (
+                track.track.get_display_name(),
+                track.track.id.unwrap_or_default(),
+            ),
+            PlayableItem::Episode(episode) => (episode.name, episode.id.unwrap_or_default()),
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use reqwest::Method;
+use serde::Serialize;
+
+const MIN_ID_LENGTH: usize = 16;
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EndpointStats {
+    pub requests: u64,
+    pub errors: u64,
+    pub retries: u64,
+    pub rate_limited: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RequestStats {
+    endpoints: Arc<Mutex<HashMap<String, EndpointStats>>>,
+}
+
+impl RequestStats {
+    pub fn record_request(&self, endpoint: &str) {
+        self.update(endpoint, |stats| stats.requests += 1);
+    }
+
+    pub fn record_error(&self, endpoint: &str) {
+        self.update(endpoint, |stats| stats.errors += 1);
+    }
+
+    pub fn record_retry(&self, endpoint: &str) {
+        self.update(endpoint, |stats| stats.retries += 1);
+    }
+
+    pub fn record_rate_limited(&self, endpoint: &str) {
+        self.update(endpoint, |stats| stats.rate_limited += 1);
+    }
+
+    pub fn get_endpoint_stats(&self) -> Vec<(String, EndpointStats)> {
+        let endpoints = match self.endpoints.lock() {
+            Ok(endpoints) => endpoints,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let mut stats: Vec<(String, EndpointStats)> = endpoints
+            .iter()
+            .map(|(endpoint, stats)| (endpoint.clone(), stats.clone()))
+            .collect();
+
+        stats.sort_by(|a, b| a.0.cmp(&b.0));
+        stats
+    }
+
+    pub fn get_endpoint_key(method: &Method, route: &str) -> String {
+        let path = route
+            .split('?')
+            .next()
+            .unwrap_or_default()
+            .trim_start_matches('/');
+        let segments: Vec<&str> = path
+            .split('/')
+            .map(|segment| {
+                let is_id = segment.len() >= MIN_ID_LENGTH
+                    && segment.chars().all(|char| char.is_ascii_alphanumeric());
+
+                match is_id {
+                    true => "{id}",
+                    false => segment,
+                }
+            })
+            .collect();
+
+        format!("{} {}", method, segments.join("/"))
+    }
+
+    fn update(&self, endpoint: &str, update: impl FnOnce(&mut EndpointStats)) {
+        let mut endpoints = match self.endpoints.lock() {
+            Ok(endpoints) => endpoints,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        update(endpoints.entry(endpoint.to_string()).or_default());
+    }
+}
@@ -0,0 +1,159 @@
+use reqwest::Url;
+
+use crate::core::app::AppResult;
+
+use super::{client::SpotifyClient, models::search::SearchResponse, NameAndId};
+
+const SEARCH_PAGE_LIMIT: u64 = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchType {
+    Track,
+    Album,
+    Artist,
+    Playlist,
+}
+
+impl SearchType {
+    pub fn get_type_string(&self) -> &str {
+        match self {
+            SearchType::Track => "track",
+            SearchType::Album => "album",
+            SearchType::Artist => "artist",
+            SearchType::Playlist => "playlist",
+        }
+    }
+
+    pub fn get_title(&self) -> &str {
+        match self {
+            SearchType::Track => "Songs",
+            SearchType::Album => "Albums",
+            SearchType::Artist => "Artists",
+            SearchType::Playlist => "Playlists",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Search {
+    pub query: String,
+    pub search_type: SearchType,
+    pub results: Vec<NameAndId>,
+    pub total: u64,
+    loaded_query: Option<String>,
+    next: Option<String>,
+}
+
+impl Search {
+    pub fn new(search_type: SearchType) -> Self {
+        Self {
+            query: String::new(),
+            search_type,
+            results: vec![],
+            total: 0,
+            loaded_query: None,
+            next: None,
+        }
+    }
+
+    pub fn set_query(&mut self, query: impl Into<String>) {
+        self.query = query.into();
+        self.results = vec![];
+        self.total = 0;
+        self.loaded_query = None;
+        self.next = None;
+    }
+
+    pub async fn refresh(&mut self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
+        let mut url = Url::parse(&format!("{}/search", spotify_client.config.get_api_url()))?;
+
+        url.query_pairs_mut()
+            .append_pair("q", &self.query)
+            .append_pair("type", self.search_type.get_type_string())
+            .append_pair("limit", &SEARCH_PAGE_LIMIT.to_string());
+
+        let query = self.query.clone();
+        let response = spotify_client
+            .get_json::<SearchResponse>(url.as_str())
+            .await?;
+
+        self.results = vec![];
+        self.add_page(response);
+        self.loaded_query = Some(query);
+
+        Ok(())
+    }
+
+    pub async fn next_page(&mut self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
+        if let Some(next) = self.next.take() {
+            let response = spotify_client.get_json::<SearchResponse>(&next).await?;
+
+            self.add_page(response);
+        }
+
+        Ok(())
+    }
+
+    pub fn needs_refresh(&self) -> bool {
+        !self.query.is_empty() && self.loaded_query.as_ref() != Some(&self.query)
+    }
+
+    pub fn is_loaded(&self) -> bool {
+        self.loaded_query.is_some()
+    }
+
+    pub fn has_next_page(&self) -> bool {
+        self.next.is_some()
+    }
+
+    fn add_page(&mut self, response: SearchResponse) {
+        match self.search_type {
+            SearchType::Track => {
+                if let Some(page) = response.tracks {
+                    self.next = page.next;
+                    self.total = page.total;
+                    self.results.extend(page.items.into_iter().map(|track| {
+                        (
+                            track.track.get_display_name(),
+                            track.track.id.unwrap_or_default(),
+                        )
+                    }));
+                }
+            }
+            SearchType::Album => {
+                if let Some(page) = response.albums {
+                    self.next = page.next;
+                    self.total = page.total;
+                    self.results.extend(
+                        page.items
+                            .into_iter()
+                            .map(|album| (album.get_display_name(), album.id.unwrap_or_default())),
+                    );
+                }
+            }
+            SearchType::Artist => {
+                if let Some(page) = response.artists {
+                    self.next = page.next;
+                    self.total = page.total;
+                    self.results.extend(
+                        page.items.into_iter().map(|artist| {
+                            (artist.artist.name, artist.artist.id.unwrap_or_default())
+                        }),
+                    );
+                }
+            }
+            SearchType::Playlist => {
+                if let Some(page) = response.playlists {
+                    self.next = page.next;
+                    self.total = page.total;
+                    self.results.extend(
+                        page.items
+                            .into_iter()
+                            .flatten()
+                            .map(|playlist| (playlist.get_display_name(), playlist.id)),
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,32 +1,90 @@
-use serde_json::Value;
+use log::warn;
 
-use crate::{core::app::AppResult, utils::value::GetOrDefault};
+use crate::{core::app::AppResult, utils::time::milliseconds_to_string};
 
-use super::client::SpotifyClient;
+use super::{
+    client::SpotifyClient,
+    models::{common::ImageObject, track::TrackObject},
+};
+
+const MAX_BATCH_IDS: usize = 50;
+const SAVED_ROUTE: &str = "me/tracks/contains";
 
 #[derive(Debug, Clone)]
 pub struct Song {
     pub id: String,
+    pub uri: String,
     pub name: String,
     pub artist_names: Vec<String>,
+    pub artist_ids: Vec<String>,
+    pub album_id: String,
     pub album_name: String,
     pub album_year: String,
+    pub images: Vec<ImageObject>,
     pub song_length: u64,
     pub disk_number: u64,
     pub track_number: u64,
+    pub explicit: bool,
+    pub popularity: u64,
+    pub is_saved: Option<bool>,
 }
 
 impl Default for Song {
     fn default() -> Self {
         Self {
             id: String::new(),
+            uri: String::new(),
             name: String::new(),
             artist_names: vec![],
+            artist_ids: vec![],
+            album_id: String::new(),
             album_name: String::new(),
             album_year: String::new(),
+            images: vec![],
             song_length: 0,
             disk_number: 0,
             track_number: 0,
+            explicit: false,
+            popularity: 0,
+            is_saved: None,
+        }
+    }
+}
+
+impl From<TrackObject> for Song {
+    fn from(track: TrackObject) -> Self {
+        let mut album_year = track.album.release_date.unwrap_or_default();
+
+        if album_year.len() > 5 {
+            album_year = album_year[0..4].to_string();
+        }
+
+        Self {
+            id: track.track.id.unwrap_or_default(),
+            uri: track.track.uri.unwrap_or_default(),
+            name: track.track.name,
+            artist_names: track
+                .track
+                .artists
+                .iter()
+                .map(|artist| artist.name.clone())
+                .collect(),
+            artist_ids: track
+                .track
+                .artists
+                .iter()
+                .filter_map(|artist| artist.id.clone())
+                .collect(),
+            album_id: track.album.id.unwrap_or_default(),
+            album_name: track.album.name,
+            album_year,
+            images: track.album.images,
+            song_length: track.track.duration_ms,
+            disk_number: track.track.disc_number,
+            track_number: track.track.track_number,
+            explicit: track.track.explicit,
+            popularity: track.popularity,
+            is_saved: None,
         }
     }
 }
@@ -35,57 +93,78 @@ impl Song {
     pub fn new(id: String) -> Self {
         Self {
             id,
-            name: String::new(),
-            artist_names: vec![],
-            album_name: String::new(),
-            album_year: String::new(),
-            song_length: 0,
-            disk_number: 0,
-            track_number: 0,
+            ..Self::default()
         }
     }
 
+    pub async fn prefetch(spotify_client: &mut SpotifyClient, ids: &[String]) -> AppResult<()> {
+        spotify_client
+            .get_cached_batch::<TrackObject>("tracks", ids, MAX_BATCH_IDS)
+            .await?;
+        spotify_client
+            .get_cached_flags(SAVED_ROUTE, ids, MAX_BATCH_IDS)
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn refresh(&mut self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
         let url = format!("tracks/{}", self.id);
-        let response = spotify_client.get(&url).await?;
-        let json = response.json::<Value>().await?;
+        let track = spotify_client.get_cached_json::<TrackObject>(&url).await?;
 
-        let name = json.get_string_or_default("name");
-        let mut artist_names = Vec::<String>::new();
-        let mut album_name = String::new();
-        let mut album_year = String::new();
-        let song_length = json.get_number_or_default("duration_ms");
-        let disk_number = json.get_number_or_default("disc_number");
-        let track_number = json.get_number_or_default("track_number");
+        *self = Song::from(track);
 
-        let artists_array = json.get_array_or_default("artists");
+        if let Err(error) = self.refresh_saved(spotify_client).await {
+            warn!("Could not check whether the song is saved: {}", error);
+            self.is_saved = None;
+        }
 
-        for artist_value in artists_array {
-            let artist = artist_value.get_string_or_default("name");
+        Ok(())
+    }
 
-            artist_names.push(artist);
-        }
+    pub async fn refresh_saved(&mut self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
+        let ids = [self.id.clone()];
+        let saved = spotify_client
+            .get_cached_flags(SAVED_ROUTE, &ids, MAX_BATCH_IDS)
+            .await?;
 
-        if let Some(album) = json.get("album") {
-            album_name = album.get_string_or_default("name");
-            album_year = album.get_string_or_default("release_date");
+        self.is_saved = saved.get(&self.id).copied();
 
-            if album_year.len() > 5 {
-                album_year = album_year[0..4].to_string();
-            }
-        }
+        Ok(())
+    }
 
-        self.name = name;
-        self.artist_names = artist_names;
-        self.album_name = album_name;
-        self.album_year = album_year;
-        self.disk_number = disk_number;
-        self.song_length = song_length;
-        self.track_number = track_number;
+    pub async fn toggle_saved(&mut self, spotify_client: &mut SpotifyClient) -> AppResult<()> {
+        let is_saved = self.is_saved.unwrap_or_default();
+
+        self.set_saved(spotify_client, !is_saved).await
+    }
+
+    pub async fn set_saved(
+        &mut self,
+        spotify_client: &mut SpotifyClient,
+        is_saved: bool,
+    ) -> AppResult<()> {
+        let url = format!("me/tracks?ids={}", self.id);
+
+        match is_saved {
+            true => spotify_client.put(&url, None).await?,
+            false => spotify_client.delete(&url, None).await?,
+        };
+
+        spotify_client.set_cached_flag(SAVED_ROUTE, &self.id, is_saved);
+        self.is_saved = Some(is_saved);
 
         Ok(())
     }
 
+    pub fn get_saved_marker(&self) -> &str {
+        match self.is_saved {
+            Some(true) => " ♥",
+            Some(false) => " ♡",
+            None => "",
+        }
+    }
+
     pub fn get_artists_string(&self) -> String {
         let mut artists_string = String::new();
 
@@ -101,7 +180,7 @@ impl Song {
     }
 
     pub fn get_song_length_string(&self) -> String {
-        self.milliseconds_to_string(self.song_length)
+        milliseconds_to_string(self.song_length)
     }
 
     pub fn is_empty(&self) -> bool {
@@ -111,12 +190,4 @@ impl Song {
             || self.song_length == 0
             || self.track_number == 0
     }
-
-    fn milliseconds_to_string(&self, ms: u64) -> String {
-        let total_seconds = ms / 1_000;
-        let minutes = total_seconds / 60;
-        let seconds = total_seconds % 60;
-
-        format!("{}:{:02}", minutes, seconds)
-    }
 }
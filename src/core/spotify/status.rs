@@ -0,0 +1,171 @@
+use serde::Serialize;
+
+use crate::{core::app::AppResult, utils::time::milliseconds_to_string};
+
+use super::{
+    client::SpotifyClient,
+    models::{playback::PlaybackStateObject, track::PlayableItem},
+    song::Song,
+};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlayerStatus {
+    pub status: String,
+    pub title: String,
+    pub artist: String,
+    pub album: String,
+    pub id: String,
+    pub uri: String,
+    pub progress: String,
+    pub duration: String,
+    pub progress_ms: u64,
+    pub duration_ms: u64,
+    pub shuffle: bool,
+    pub repeat: String,
+    pub volume: Option<u64>,
+    pub device: Option<String>,
+    pub is_saved: Option<bool>,
+}
+
+impl Default for PlayerStatus {
+    fn default() -> Self {
+        Self {
+            status: "stopped".to_string(),
+            title: String::new(),
+            artist: String::new(),
+            album: String::new(),
+            id: String::new(),
+            uri: String::new(),
+            progress: String::new(),
+            duration: String::new(),
+            progress_ms: 0,
+            duration_ms: 0,
+            shuffle: false,
+            repeat: "off".to_string(),
+            volume: None,
+            device: None,
+            is_saved: None,
+        }
+    }
+}
+
+impl PlayerStatus {
+    pub async fn refresh(
+        spotify_client: &mut SpotifyClient,
+        include_saved: bool,
+    ) -> AppResult<Self> {
+        let response = spotify_client.get("me/player").await?;
+
+        if response.status() == 204 {
+            return Ok(Self::default());
+        }
+
+        let playback_state =
+            SpotifyClient::parse_json::<PlaybackStateObject>("me/player", response).await?;
+        let progress_ms = playback_state.progress_ms.unwrap_or_default();
+        let device = playback_state.device;
+        let status = Self {
+            status: "idle".to_string(),
+            progress: milliseconds_to_string(progress_ms),
+            progress_ms,
+            shuffle: playback_state.shuffle_state,
+            repeat: playback_state.repeat_state,
+            volume: device.as_ref().and_then(|device| device.volume_percent),
+            device: device.map(|device| device.name),
+            ..Self::default()
+        };
+
+        let mut song = match playback_state.item {
+            Some(PlayableItem::Track(track)) => Song::from(*track),
+            Some(PlayableItem::Episode(episode)) => Song {
+                id: episode.id.unwrap_or_default(),
+                uri: episode.uri.unwrap_or_default(),
+                name: episode.name,
+                song_length: episode.duration_ms,
+                ..Song::default()
+            },
+            None => return Ok(status),
+        };
+
+        if include_saved && !song.album_id.is_empty() {
+            song.refresh_saved(spotify_client).await?;
+        }
+
+        let playing_status = match playback_state.is_playing {
+            true => "playing",
+            false => "paused",
+        };
+
+        Ok(Self {
+            status: playing_status.to_string(),
+            title: song.name.clone(),
+            artist: song.get_artists_string(),
+            album: song.album_name.clone(),
+            id: song.id.clone(),
+            uri: song.uri.clone(),
+            duration: song.get_song_length_string(),
+            duration_ms: song.song_length,
+            is_saved: song.is_saved,
+            ..status
+        })
+    }
+
+    pub fn format(&self, template: &str) -> String {
+        let shuffle = match self.shuffle {
+            true => "on",
+            false => "off",
+        };
+        let volume = self.volume.map(|volume| volume.to_string());
+        let is_saved = match self.is_saved {
+            Some(true) => "♥",
+            Some(false) => "♡",
+            None => "",
+        };
+
+        let values = [
+            ("{status}", self.status.as_str()),
+            ("{title}", self.title.as_str()),
+            ("{artist}", self.artist.as_str()),
+            ("{album}", self.album.as_str()),
+            ("{id}", self.id.as_str()),
+            ("{uri}", self.uri.as_str()),
+            ("{progress}", self.progress.as_str()),
+            ("{duration}", self.duration.as_str()),
+            ("{shuffle}", shuffle),
+            ("{repeat}", self.repeat.as_str()),
+            ("{volume}", volume.as_deref().unwrap_or_default()),
+            ("{device}", self.device.as_deref().unwrap_or_default()),
+            ("{saved}", is_saved),
+        ];
+
+        let mut output = template.to_string();
+
+        for (key, value) in values {
+            output = output.replace(key, value);
+        }
+
+        output
+    }
+
+    pub fn to_text(&self) -> String {
+        match self.status.as_str() {
+            "stopped" => return "Nothing is playing".to_string(),
+            "idle" => return self.format("No track is loaded\nDevice: {device}"),
+            _ => {}
+        }
+
+        let lines = [
+            format!("Status: {}", self.status),
+            format!("Song: {}", self.title),
+            format!("Artists: {}", self.artist),
+            format!("Album: {}", self.album),
+            format!("Progress: {}/{}", self.progress, self.duration),
+            self.format("Shuffle: {shuffle}"),
+            format!("Repeat: {}", self.repeat),
+            self.format("Volume: {volume}%"),
+            self.format("Device: {device}"),
+        ];
+
+        lines.join("\n")
+    }
+}
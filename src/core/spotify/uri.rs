@@ -0,0 +1,131 @@
+use std::fmt::{self, Display};
+
+use color_eyre::eyre::eyre;
+use reqwest::Url;
+
+use crate::core::app::AppResult;
+
+const WEB_HOST: &str = "open.spotify.com";
+const URI_TYPES: [&str; 6] = ["track", "episode", "album", "artist", "playlist", "show"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpotifyUri {
+    pub uri_type: String,
+    pub id: String,
+}
+
+impl SpotifyUri {
+    pub fn parse(input: &str) -> AppResult<Self> {
+        let input = input.trim();
+
+        let parts: Vec<String> = match input.starts_with("spotify:") {
+            true => input
+                .split(':')
+                .skip(1)
+                .map(|part| part.to_string())
+                .collect(),
+            false => match Url::parse(input) {
+                Ok(url) if url.host_str() == Some(WEB_HOST) => url
+                    .path_segments()
+                    .map(|segments| {
+                        segments
+                            .filter(|segment| {
+                                !segment.is_empty()
+                                    && *segment != "embed"
+                                    && !segment.starts_with("intl-")
+                            })
+                            .map(|segment| segment.to_string())
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                _ => vec![],
+            },
+        };
+
+        match parts.as_slice() {
+            [uri_type, id]
+                if URI_TYPES.contains(&uri_type.as_str())
+                    && !id.is_empty()
+                    && id.chars().all(|c| c.is_ascii_alphanumeric()) =>
+            {
+                Ok(Self {
+                    uri_type: uri_type.to_string(),
+                    id: id.to_string(),
+                })
+            }
+            _ => Err(eyre!(
+                "`{}` is not a Spotify URI or open.spotify.com URL for a {}.",
+                input,
+                URI_TYPES.join(", ")
+            )),
+        }
+    }
+
+    pub fn is_playable_item(&self) -> bool {
+        self.uri_type == "track" || self.uri_type == "episode"
+    }
+}
+
+impl Display for SpotifyUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "spotify:{}:{}", self.uri_type, self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_uri(uri_type: &str, id: &str) -> SpotifyUri {
+        SpotifyUri {
+            uri_type: uri_type.to_string(),
+            id: id.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_spotify_uri() {
+        let uri = SpotifyUri::parse(" spotify:track:4uLU6hMCjMI75M1A2tKUQC ").unwrap();
+
+        assert_eq!(uri, get_uri("track", "4uLU6hMCjMI75M1A2tKUQC"));
+        assert_eq!(uri.to_string(), "spotify:track:4uLU6hMCjMI75M1A2tKUQC");
+    }
+
+    #[test]
+    fn parses_open_spotify_url() {
+        let uri =
+            SpotifyUri::parse("https://open.spotify.com/intl-de/album/1DFixLWuPkv3KT3TnV35m3?si=a")
+                .unwrap();
+        let embed_uri =
+            SpotifyUri::parse("https://open.spotify.com/embed/playlist/37i9dQZF1DXcBWIGoYBM5M")
+                .unwrap();
+
+        assert_eq!(uri, get_uri("album", "1DFixLWuPkv3KT3TnV35m3"));
+        assert_eq!(embed_uri, get_uri("playlist", "37i9dQZF1DXcBWIGoYBM5M"));
+    }
+
+    #[test]
+    fn rejects_other_hosts() {
+        assert!(SpotifyUri::parse("https://example.com/track/4uLU6hMCjMI75M1A2tKUQC").is_err());
+        assert!(SpotifyUri::parse(
+            "https://open.spotify.com.example.com/track/4uLU6hMCjMI75M1A2tKUQC"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_uris() {
+        assert!(SpotifyUri::parse("spotify:user:someone").is_err());
+        assert!(SpotifyUri::parse("spotify:track:").is_err());
+        assert!(SpotifyUri::parse("spotify:track:abc-def").is_err());
+        assert!(SpotifyUri::parse("spotify:track:abc:def").is_err());
+        assert!(SpotifyUri::parse("not a uri").is_err());
+    }
+
+    #[test]
+    fn only_tracks_and_episodes_are_playable() {
+        assert!(get_uri("track", "a").is_playable_item());
+        assert!(get_uri("episode", "a").is_playable_item());
+        assert!(!get_uri("album", "a").is_playable_item());
+    }
+}
@@ -0,0 +1,316 @@
+use std::{collections::HashMap, future::Future};
+
+use tokio::{
+    sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
+    task::JoinHandle,
+};
+
+use crate::components::Component;
+
+use super::{
+    app::AppResult,
+    message::Message,
+    spotify::{
+        album::Album, artist::Artist, device::Device, library::Library, playlist::Playlist,
+        queue::Queue, search::Search, song::Song,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaskKey {
+    Device,
+    Song,
+    Album,
+    Artist,
+    Playlist,
+    Search,
+    SearchPage,
+    Queue,
+    Library,
+    LibraryPage,
+    Player,
+    PlaylistEdit,
+    SavedSong,
+    SavedAlbum,
+    Prefetch,
+    Auth,
+}
+
+impl TaskKey {
+    pub const REFRESH_KEYS: [TaskKey; 10] = [
+        TaskKey::Device,
+        TaskKey::Song,
+        TaskKey::Album,
+        TaskKey::Artist,
+        TaskKey::Playlist,
+        TaskKey::Search,
+        TaskKey::SearchPage,
+        TaskKey::Queue,
+        TaskKey::Library,
+        TaskKey::LibraryPage,
+    ];
+
+    pub fn is_refresh(&self) -> bool {
+        Self::REFRESH_KEYS.contains(self)
+    }
+
+    pub fn get_retry_message(&self) -> Option<Message> {
+        match self {
+            TaskKey::Device => Some(Message::RefreshDevice),
+            TaskKey::Song => Some(Message::RefreshSong),
+            TaskKey::Album => Some(Message::RefreshAlbum),
+            TaskKey::Artist => Some(Message::RefreshArtist),
+            TaskKey::Playlist => Some(Message::RefreshPlaylist),
+            TaskKey::Search => Some(Message::RefreshSearch),
+            TaskKey::SearchPage => Some(Message::NextSearchPage),
+            TaskKey::Queue => Some(Message::RefreshQueue),
+            TaskKey::Library => Some(Message::RefreshLibrary),
+            TaskKey::LibraryPage => Some(Message::NextLibraryPage),
+            _ => None,
+        }
+    }
+
+    pub fn get_target(&self, component: &mut dyn Component) -> Option<String> {
+        match self {
+            TaskKey::Device => component.get_device().map(|_| String::new()),
+            TaskKey::Queue => component.get_queue().map(|_| String::new()),
+            TaskKey::Song | TaskKey::SavedSong => component.get_song().map(|song| song.id.clone()),
+            TaskKey::Album | TaskKey::SavedAlbum => {
+                component.get_album().map(|album| album.id.clone())
+            }
+            TaskKey::Artist => component.get_artist().map(|artist| artist.id.clone()),
+            TaskKey::Playlist | TaskKey::PlaylistEdit => {
+                component.get_playlist().map(|playlist| playlist.id.clone())
+            }
+            TaskKey::Search => component
+                .get_search()
+                .map(|search| format!("{}:{}", search.search_type.get_type_string(), search.query)),
+            TaskKey::SearchPage => component.get_search().map(|search| {
+                format!(
+                    "{}:{}:{}",
+                    search.search_type.get_type_string(),
+                    search.query,
+                    search.results.len()
+                )
+            }),
+            TaskKey::Library => component
+                .get_library()
+                .map(|library| library.library_type.get_title().to_string()),
+            TaskKey::LibraryPage => component.get_library().map(|library| {
+                format!(
+                    "{}:{}",
+                    library.library_type.get_title(),
+                    library.items.len()
+                )
+            }),
+            TaskKey::Player | TaskKey::Auth => Some(String::new()),
+            TaskKey::Prefetch => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum TaskOutput {
+    None,
+    LoggedIn,
+    Device(Device),
+    Song(Song),
+    SongSaved(Option<bool>),
+    Album(Album),
+    AlbumSaved(Option<bool>),
+    Artist(Artist),
+    Playlist(Playlist),
+    PlaylistCreated(String),
+    AddedToPlaylist,
+    Search(Search),
+    Queue(Queue),
+    Library(Library),
+}
+
+impl TaskOutput {
+    pub fn apply(&self, component: &mut dyn Component) {
+        match self {
+            TaskOutput::Device(device) => {
+                if let Some(current) = component.get_device() {
+                    *current = device.clone();
+                }
+            }
+            TaskOutput::Song(song) => {
+                if let Some(current) = component.get_song() {
+                    *current = song.clone();
+                }
+            }
+            TaskOutput::SongSaved(is_saved) => {
+                if let Some(current) = component.get_song() {
+                    current.is_saved = *is_saved;
+                }
+            }
+            TaskOutput::Album(album) => {
+                if let Some(current) = component.get_album() {
+                    *current = album.clone();
+                }
+            }
+            TaskOutput::AlbumSaved(is_saved) => {
+                if let Some(current) = component.get_album() {
+                    current.is_saved = *is_saved;
+                }
+            }
+            TaskOutput::Artist(artist) => {
+                if let Some(current) = component.get_artist() {
+                    *current = artist.clone();
+                }
+            }
+            TaskOutput::Playlist(playlist) => {
+                if let Some(current) = component.get_playlist() {
+                    *current = playlist.clone();
+                }
+            }
+            TaskOutput::Search(search) => {
+                if let Some(current) = component.get_search() {
+                    *current = search.clone();
+                }
+            }
+            TaskOutput::Queue(queue) => {
+                if let Some(current) = component.get_queue() {
+                    *current = queue.clone();
+                }
+            }
+            TaskOutput::Library(library) => {
+                if let Some(current) = component.get_library() {
+                    *current = library.clone();
+                }
+            }
+            TaskOutput::None
+            | TaskOutput::LoggedIn
+            | TaskOutput::PlaylistCreated(_)
+            | TaskOutput::AddedToPlaylist => {}
+        }
+    }
+}
+
+pub struct TaskEvent {
+    pub key: TaskKey,
+    pub target: String,
+    pub id: u64,
+    pub result: AppResult<TaskOutput>,
+}
+
+struct RunningTask {
+    id: u64,
+    handle: JoinHandle<()>,
+}
+
+pub struct TaskManager {
+    sender: UnboundedSender<TaskEvent>,
+    receiver: UnboundedReceiver<TaskEvent>,
+    running: HashMap<(TaskKey, String), RunningTask>,
+    deferred: Vec<(TaskKey, Message)>,
+    next_id: u64,
+}
+
+impl Default for TaskManager {
+    fn default() -> Self {
+        let (sender, receiver) = unbounded_channel();
+
+        Self {
+            sender,
+            receiver,
+            running: HashMap::new(),
+            deferred: vec![],
+            next_id: 0,
+        }
+    }
+}
+
+impl TaskManager {
+    pub fn spawn<F>(&mut self, key: TaskKey, target: String, future: F)
+    where
+        F: Future<Output = AppResult<TaskOutput>> + Send + 'static,
+    {
+        let id = self.next_id;
+        let sender = self.sender.clone();
+        let event_target = target.clone();
+
+        self.next_id += 1;
+
+        let handle = tokio::spawn(async move {
+            let result = future.await;
+
+            let _ = sender.send(TaskEvent {
+                key,
+                target: event_target,
+                id,
+                result,
+            });
+        });
+
+        if let Some(previous) = self
+            .running
+            .insert((key, target), RunningTask { id, handle })
+        {
+            previous.handle.abort();
+        }
+    }
+
+    pub fn is_running(&self, key: TaskKey) -> bool {
+        self.running
+            .keys()
+            .any(|(running_key, _)| *running_key == key)
+    }
+
+    pub fn is_running_for(&self, key: TaskKey, target: &str) -> bool {
+        self.running.contains_key(&(key, target.to_string()))
+    }
+
+    pub async fn recv(&mut self) -> Option<TaskEvent> {
+        self.receiver.recv().await
+    }
+
+    pub fn finish(&mut self, event: &TaskEvent) -> bool {
+        let task_key = (event.key, event.target.clone());
+
+        match self.running.get(&task_key) {
+            Some(running) if running.id == event.id => {
+                self.running.remove(&task_key);
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn cancel_unwanted(&mut self, wanted: &[(TaskKey, String)]) {
+        let unwanted: Vec<(TaskKey, String)> = self
+            .running
+            .keys()
+            .filter(|(key, target)| {
+                key.is_refresh() && !wanted.contains(&(*key, target.to_string()))
+            })
+            .cloned()
+            .collect();
+
+        for task_key in unwanted {
+            if let Some(running) = self.running.remove(&task_key) {
+                running.handle.abort();
+            }
+        }
+    }
+
+    pub fn defer(&mut self, key: TaskKey, message: Message) {
+        self.deferred.push((key, message));
+    }
+
+    pub fn take_deferred(&mut self, key: TaskKey) -> Option<Message> {
+        let index = self
+            .deferred
+            .iter()
+            .position(|(deferred_key, _)| *deferred_key == key)?;
+
+        Some(self.deferred.remove(index).1)
+    }
+
+    pub fn clear_deferred(&mut self, key: TaskKey) {
+        self.deferred
+            .retain(|(deferred_key, _)| *deferred_key != key);
+    }
+}
@@ -2,6 +2,7 @@ pub mod auth;
 pub mod components;
 pub mod core;
 pub mod layout;
+pub mod mock;
 pub mod screens;
 pub mod utils;
 pub mod widgets;
@@ -6,5 +6,9 @@ async fn main() -> AppResult<()> {
 
     app.run().await?;
 
+    if app.exit_code != 0 {
+        std::process::exit(app.exit_code);
+    }
+
     Ok(())
 }
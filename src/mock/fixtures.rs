@@ -0,0 +1,457 @@
+use std::{collections::HashMap, fs};
+
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+use crate::core::app::AppResult;
+
+const DEFAULT_FIXTURES: &str = include_str!("fixtures/default.json");
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Fixtures {
+    #[serde(default)]
+    pub user: Value,
+    #[serde(default)]
+    pub devices: Vec<Value>,
+    #[serde(default)]
+    pub artists: Vec<FixtureArtist>,
+    #[serde(default)]
+    pub albums: Vec<FixtureAlbum>,
+    #[serde(default)]
+    pub tracks: Vec<FixtureTrack>,
+    #[serde(default)]
+    pub playlists: Vec<FixturePlaylist>,
+    #[serde(default)]
+    pub saved_tracks: Vec<String>,
+    #[serde(default)]
+    pub saved_albums: Vec<String>,
+    #[serde(default)]
+    pub followed_artists: Vec<String>,
+    #[serde(default)]
+    pub playback: FixturePlayback,
+    #[serde(default)]
+    pub responses: Vec<ScriptedResponse>,
+    #[serde(default = "default_token_expires_in")]
+    pub token_expires_in: u64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct FixtureArtist {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub genres: Vec<String>,
+    #[serde(default)]
+    pub followers: u64,
+    #[serde(default)]
+    pub popularity: u64,
+    #[serde(default)]
+    pub monthly_listeners: u64,
+    #[serde(default)]
+    pub top_tracks: Vec<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct FixtureAlbum {
+    pub id: String,
+    pub name: String,
+    #[serde(default = "default_album_type")]
+    pub album_type: String,
+    #[serde(default)]
+    pub release_date: String,
+    #[serde(default)]
+    pub artists: Vec<String>,
+    #[serde(default)]
+    pub tracks: Vec<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct FixtureTrack {
+    pub id: String,
+    pub name: String,
+    pub duration_ms: u64,
+    pub album: String,
+    #[serde(default)]
+    pub artists: Vec<String>,
+    #[serde(default)]
+    pub explicit: bool,
+    #[serde(default)]
+    pub popularity: u64,
+    #[serde(default = "default_disc_number")]
+    pub disc_number: u64,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct FixturePlaylist {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default = "default_playlist_owner")]
+    pub owner: String,
+    #[serde(default = "default_public")]
+    pub public: bool,
+    #[serde(default)]
+    pub collaborative: bool,
+    #[serde(default)]
+    pub followers: u64,
+    #[serde(default)]
+    pub tracks: Vec<String>,
+    #[serde(default)]
+    pub snapshot: u64,
+}
+
+impl FixturePlaylist {
+    pub fn get_snapshot_id(&self) -> String {
+        format!("mock-snapshot-{}", self.snapshot)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct FixturePlayback {
+    pub device_id: Option<String>,
+    pub context_uri: Option<String>,
+    pub track_id: Option<String>,
+    #[serde(default)]
+    pub progress_ms: u64,
+    #[serde(default)]
+    pub is_playing: bool,
+    #[serde(default)]
+    pub shuffle_state: bool,
+    #[serde(default = "default_repeat_state")]
+    pub repeat_state: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ScriptedResponse {
+    pub method: Option<String>,
+    pub path: String,
+    #[serde(default = "default_status")]
+    pub status: u16,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    pub body: Option<Value>,
+    pub times: Option<u32>,
+}
+
+impl ScriptedResponse {
+    pub fn matches(&self, method: &str, path: &str) -> bool {
+        if let Some(expected_method) = &self.method {
+            if !expected_method.eq_ignore_ascii_case(method) {
+                return false;
+            }
+        }
+
+        match self.path.strip_suffix('*') {
+            Some(prefix) => path.starts_with(prefix),
+            None => path == self.path,
+        }
+    }
+}
+
+impl Fixtures {
+    pub fn load(path: Option<&str>) -> AppResult<Self> {
+        let data = match path {
+            Some(path) => fs::read_to_string(path)?,
+            None => DEFAULT_FIXTURES.to_string(),
+        };
+
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    pub fn get_track(&self, id: &str) -> Option<&FixtureTrack> {
+        self.tracks.iter().find(|track| track.id == id)
+    }
+
+    pub fn get_album(&self, id: &str) -> Option<&FixtureAlbum> {
+        self.albums.iter().find(|album| album.id == id)
+    }
+
+    pub fn get_artist(&self, id: &str) -> Option<&FixtureArtist> {
+        self.artists.iter().find(|artist| artist.id == id)
+    }
+
+    pub fn get_playlist(&self, id: &str) -> Option<&FixturePlaylist> {
+        self.playlists.iter().find(|playlist| playlist.id == id)
+    }
+
+    pub fn get_playlist_mut(&mut self, id: &str) -> Option<&mut FixturePlaylist> {
+        self.playlists.iter_mut().find(|playlist| playlist.id == id)
+    }
+
+    pub fn get_track_duration(&self, id: &str) -> u64 {
+        self.get_track(id)
+            .map(|track| track.duration_ms)
+            .unwrap_or(0)
+    }
+
+    pub fn get_context_tracks(&self, context_uri: &str) -> Vec<String> {
+        let parts: Vec<&str> = context_uri.split(':').collect();
+
+        match parts.as_slice() {
+            ["spotify", "album", id] => self
+                .get_album(id)
+                .map(|album| album.tracks.clone())
+                .unwrap_or_default(),
+            ["spotify", "artist", id] => self
+                .get_artist(id)
+                .map(|artist| artist.top_tracks.clone())
+                .unwrap_or_default(),
+            ["spotify", "playlist", id] => self
+                .get_playlist(id)
+                .map(|playlist| playlist.tracks.clone())
+                .unwrap_or_default(),
+            _ => vec![],
+        }
+    }
+
+    pub fn artist_object(&self, id: &str) -> Option<Value> {
+        let artist = self.get_artist(id)?;
+        let mut object = Self::simplified_artist_value(artist);
+
+        object.insert("genres".into(), json!(artist.genres));
+        object.insert(
+            "followers".into(),
+            json!({ "href": null, "total": artist.followers }),
+        );
+        object.insert("popularity".into(), json!(artist.popularity));
+        object.insert("images".into(), json!([]));
+        Self::extend(&mut object, &artist.extra);
+
+        Some(Value::Object(object))
+    }
+
+    pub fn simplified_artist(&self, id: &str) -> Option<Value> {
+        self.get_artist(id)
+            .map(|artist| Value::Object(Self::simplified_artist_value(artist)))
+    }
+
+    pub fn album_object(&self, id: &str, tracks: Value) -> Option<Value> {
+        let album = self.get_album(id)?;
+        let mut object = self.simplified_album_value(album);
+
+        object.insert("tracks".into(), tracks);
+        object.insert("genres".into(), json!([]));
+        object.insert("label".into(), json!("Mock Records"));
+        object.insert("popularity".into(), json!(50));
+
+        Some(Value::Object(object))
+    }
+
+    pub fn simplified_album(&self, id: &str) -> Option<Value> {
+        self.get_album(id)
+            .map(|album| Value::Object(self.simplified_album_value(album)))
+    }
+
+    pub fn track_object(&self, id: &str) -> Option<Value> {
+        let track = self.get_track(id)?;
+        let mut object = self.simplified_track_value(track);
+
+        object.insert(
+            "album".into(),
+            self.simplified_album(&track.album).unwrap_or(Value::Null),
+        );
+        object.insert("popularity".into(), json!(track.popularity));
+        object.insert("external_ids".into(), json!({}));
+        object.insert("is_local".into(), json!(false));
+
+        Some(Value::Object(object))
+    }
+
+    pub fn simplified_track(&self, id: &str) -> Option<Value> {
+        self.get_track(id)
+            .map(|track| Value::Object(self.simplified_track_value(track)))
+    }
+
+    pub fn simplified_playlist(&self, playlist: &FixturePlaylist, base_url: &str) -> Value {
+        let mut object = Map::new();
+        let owner_name = match self.user["id"].as_str() == Some(playlist.owner.as_str()) {
+            true => self.user["display_name"].clone(),
+            false => json!(playlist.owner),
+        };
+
+        object.insert("id".into(), json!(playlist.id));
+        object.insert("name".into(), json!(playlist.name));
+        object.insert("type".into(), json!("playlist"));
+        object.insert(
+            "uri".into(),
+            json!(format!("spotify:playlist:{}", playlist.id)),
+        );
+        object.insert("description".into(), json!(playlist.description));
+        object.insert(
+            "owner".into(),
+            json!({
+                "id": playlist.owner,
+                "display_name": owner_name,
+                "type": "user",
+                "uri": format!("spotify:user:{}", playlist.owner),
+            }),
+        );
+        object.insert("public".into(), json!(playlist.public));
+        object.insert("collaborative".into(), json!(playlist.collaborative));
+        object.insert("snapshot_id".into(), json!(playlist.get_snapshot_id()));
+        object.insert(
+            "tracks".into(),
+            json!({
+                "href": format!("{}/v1/playlists/{}/tracks", base_url, playlist.id),
+                "total": playlist.tracks.len(),
+            }),
+        );
+        object.insert("images".into(), json!([]));
+        object.insert(
+            "external_urls".into(),
+            json!({ "spotify": format!("https://open.spotify.com/playlist/{}", playlist.id) }),
+        );
+
+        Value::Object(object)
+    }
+
+    pub fn playlist_object(
+        &self,
+        playlist: &FixturePlaylist,
+        base_url: &str,
+        tracks: Value,
+    ) -> Value {
+        let mut object = self.simplified_playlist(playlist, base_url);
+
+        object["tracks"] = tracks;
+        object["followers"] = json!({ "href": null, "total": playlist.followers });
+
+        object
+    }
+
+    pub fn local_track(&self, uri: &str) -> Option<Value> {
+        let parts: Vec<String> = uri.split(':').map(|part| part.replace('+', " ")).collect();
+        let parts: Vec<&str> = parts.iter().map(String::as_str).collect();
+
+        match parts.as_slice() {
+            ["spotify", "local", artist, album, name, duration] => Some(json!({
+                "id": null,
+                "name": name,
+                "type": "track",
+                "uri": uri,
+                "duration_ms": duration.parse::<u64>().unwrap_or(0) * 1000,
+                "is_local": true,
+                "artists": [{ "id": null, "name": artist, "type": "artist", "uri": null }],
+                "album": { "id": null, "name": album, "type": "album", "uri": null },
+            })),
+            _ => None,
+        }
+    }
+
+    fn simplified_artist_value(artist: &FixtureArtist) -> Map<String, Value> {
+        let mut object = Map::new();
+
+        object.insert("id".into(), json!(artist.id));
+        object.insert("name".into(), json!(artist.name));
+        object.insert("type".into(), json!("artist"));
+        object.insert("uri".into(), json!(format!("spotify:artist:{}", artist.id)));
+        object.insert(
+            "external_urls".into(),
+            json!({ "spotify": format!("https://open.spotify.com/artist/{}", artist.id) }),
+        );
+
+        object
+    }
+
+    fn simplified_album_value(&self, album: &FixtureAlbum) -> Map<String, Value> {
+        let mut object = Map::new();
+        let artists: Vec<Value> = album
+            .artists
+            .iter()
+            .filter_map(|id| self.simplified_artist(id))
+            .collect();
+
+        object.insert("id".into(), json!(album.id));
+        object.insert("name".into(), json!(album.name));
+        object.insert("type".into(), json!("album"));
+        object.insert("album_type".into(), json!(album.album_type));
+        object.insert("album_group".into(), json!(album.album_type));
+        object.insert("uri".into(), json!(format!("spotify:album:{}", album.id)));
+        object.insert("release_date".into(), json!(album.release_date));
+        object.insert("release_date_precision".into(), json!("day"));
+        object.insert("total_tracks".into(), json!(album.tracks.len()));
+        object.insert("artists".into(), json!(artists));
+        object.insert("images".into(), json!([]));
+        object.insert(
+            "external_urls".into(),
+            json!({ "spotify": format!("https://open.spotify.com/album/{}", album.id) }),
+        );
+        Self::extend(&mut object, &album.extra);
+
+        object
+    }
+
+    fn simplified_track_value(&self, track: &FixtureTrack) -> Map<String, Value> {
+        let mut object = Map::new();
+        let artists: Vec<Value> = track
+            .artists
+            .iter()
+            .filter_map(|id| self.simplified_artist(id))
+            .collect();
+        let track_number = self
+            .get_album(&track.album)
+            .and_then(|album| album.tracks.iter().position(|id| *id == track.id))
+            .map(|position| position + 1)
+            .unwrap_or(1);
+
+        object.insert("id".into(), json!(track.id));
+        object.insert("name".into(), json!(track.name));
+        object.insert("type".into(), json!("track"));
+        object.insert("uri".into(), json!(format!("spotify:track:{}", track.id)));
+        object.insert("duration_ms".into(), json!(track.duration_ms));
+        object.insert("explicit".into(), json!(track.explicit));
+        object.insert("disc_number".into(), json!(track.disc_number));
+        object.insert("track_number".into(), json!(track_number));
+        object.insert("is_playable".into(), json!(true));
+        object.insert("preview_url".into(), Value::Null);
+        object.insert("artists".into(), json!(artists));
+        object.insert(
+            "external_urls".into(),
+            json!({ "spotify": format!("https://open.spotify.com/track/{}", track.id) }),
+        );
+        Self::extend(&mut object, &track.extra);
+
+        object
+    }
+
+    fn extend(object: &mut Map<String, Value>, extra: &Map<String, Value>) {
+        for (key, value) in extra {
+            object.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+fn default_token_expires_in() -> u64 {
+    3600
+}
+
+fn default_album_type() -> String {
+    "album".to_string()
+}
+
+fn default_playlist_owner() -> String {
+    "mock-user".to_string()
+}
+
+fn default_public() -> bool {
+    true
+}
+
+fn default_disc_number() -> u64 {
+    1
+}
+
+fn default_repeat_state() -> String {
+    "off".to_string()
+}
+
+fn default_status() -> u16 {
+    200
+}
@@ -0,0 +1,3 @@
+pub mod fixtures;
+pub mod playback;
+pub mod server;
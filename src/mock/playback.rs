@@ -0,0 +1,150 @@
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use super::fixtures::{FixturePlayback, Fixtures};
+
+#[derive(Debug, Clone)]
+pub struct Playback {
+    pub device_id: Option<String>,
+    pub context_uri: Option<String>,
+    pub tracks: Vec<String>,
+    pub queue: Vec<String>,
+    pub index: usize,
+    pub is_playing: bool,
+    pub shuffle_state: bool,
+    pub repeat_state: String,
+    pub timestamp: u64,
+    position_ms: u64,
+    anchor: Instant,
+}
+
+impl Playback {
+    pub fn new(fixture: &FixturePlayback, fixtures: &Fixtures) -> Self {
+        let mut tracks = match &fixture.context_uri {
+            Some(context_uri) => fixtures.get_context_tracks(context_uri),
+            None => vec![],
+        };
+        let mut index = 0;
+
+        if let Some(track_id) = &fixture.track_id {
+            match tracks.iter().position(|id| id == track_id) {
+                Some(position) => index = position,
+                None => tracks = vec![track_id.clone()],
+            }
+        }
+
+        Self {
+            device_id: fixture.device_id.clone(),
+            context_uri: fixture.context_uri.clone(),
+            tracks,
+            queue: vec![],
+            index,
+            is_playing: fixture.is_playing,
+            shuffle_state: fixture.shuffle_state,
+            repeat_state: fixture.repeat_state.clone(),
+            timestamp: Self::now(),
+            position_ms: fixture.progress_ms,
+            anchor: Instant::now(),
+        }
+    }
+
+    pub fn get_current_track_id(&self) -> Option<String> {
+        self.tracks.get(self.index).cloned()
+    }
+
+    pub fn get_progress_ms(&self) -> u64 {
+        match self.is_playing {
+            true => self.position_ms + self.anchor.elapsed().as_millis() as u64,
+            false => self.position_ms,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.device_id.is_some() && self.get_current_track_id().is_some()
+    }
+
+    pub fn play_tracks(&mut self, context_uri: Option<String>, tracks: Vec<String>, index: usize) {
+        self.context_uri = context_uri;
+        self.tracks = tracks;
+        self.index = index.min(self.tracks.len().saturating_sub(1));
+        self.is_playing = true;
+        self.seek(0);
+    }
+
+    pub fn pause(&mut self) {
+        self.position_ms = self.get_progress_ms();
+        self.anchor = Instant::now();
+        self.is_playing = false;
+        self.timestamp = Self::now();
+    }
+
+    pub fn resume(&mut self) {
+        self.anchor = Instant::now();
+        self.is_playing = true;
+        self.timestamp = Self::now();
+    }
+
+    pub fn seek(&mut self, position_ms: u64) {
+        self.position_ms = position_ms;
+        self.anchor = Instant::now();
+        self.timestamp = Self::now();
+    }
+
+    pub fn next(&mut self) {
+        if !self.queue.is_empty() {
+            let track_id = self.queue.remove(0);
+
+            self.tracks.insert(self.index + 1, track_id);
+            self.index += 1;
+        } else if self.index + 1 < self.tracks.len() {
+            self.index += 1;
+        } else if self.repeat_state == "context" || self.repeat_state == "track" {
+            self.index = 0;
+        } else {
+            self.pause();
+        }
+
+        self.seek(0);
+    }
+
+    pub fn previous(&mut self) {
+        if self.get_progress_ms() < 3_000 && self.index > 0 {
+            self.index -= 1;
+        }
+
+        self.seek(0);
+    }
+
+    pub fn advance(&mut self, fixtures: &Fixtures) {
+        while self.is_playing {
+            let track_id = match self.get_current_track_id() {
+                Some(track_id) => track_id,
+                None => return,
+            };
+            let duration = fixtures.get_track_duration(&track_id);
+            let progress = self.get_progress_ms();
+
+            if duration == 0 || progress < duration {
+                return;
+            }
+
+            let overflow = progress - duration;
+
+            if self.repeat_state == "track" {
+                self.seek(0);
+            } else {
+                self.next();
+            }
+
+            if self.is_playing {
+                self.position_ms = overflow;
+            }
+        }
+    }
+
+    fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or(0)
+    }
+}
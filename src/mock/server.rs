@@ -0,0 +1,1501 @@
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{Path, Query, Request, State},
+    http::{header::AUTHORIZATION, HeaderName, HeaderValue, StatusCode},
+    middleware::{from_fn, from_fn_with_state, Next},
+    response::{Html, IntoResponse, Redirect, Response},
+    routing::{get, post, put},
+    serve, Form, Json, Router,
+};
+use num_format::{Locale, ToFormattedString};
+use reqwest::Url;
+use serde_json::{json, Value};
+use tokio::{net::TcpListener, signal};
+
+use crate::{auth::pkce::Pkce, core::app::AppResult};
+
+use super::{
+    fixtures::{FixturePlaylist, Fixtures, ScriptedResponse},
+    playback::Playback,
+};
+
+const MOCK_ADDED_AT: &str = "2024-01-01T00:00:00Z";
+
+type SharedState = Arc<Mutex<MockState>>;
+type QueryParams = Query<HashMap<String, String>>;
+
+pub struct MockState {
+    fixtures: Fixtures,
+    playback: Playback,
+    devices: Vec<Value>,
+    responses: Vec<ScriptedResponse>,
+    base_url: String,
+    issued_tokens: u64,
+    code_challenge: Option<String>,
+}
+
+impl MockState {
+    fn new(fixtures: Fixtures, base_url: String) -> Self {
+        let playback = Playback::new(&fixtures.playback, &fixtures);
+
+        Self {
+            devices: fixtures.devices.clone(),
+            responses: fixtures.responses.clone(),
+            fixtures,
+            playback,
+            base_url,
+            issued_tokens: 0,
+            code_challenge: None,
+        }
+    }
+
+    fn advance(&mut self) {
+        self.playback.advance(&self.fixtures);
+    }
+
+    fn get_devices(&self) -> Vec<Value> {
+        let mut devices = self.devices.clone();
+
+        for device in devices.iter_mut() {
+            let is_active =
+                device.get("id").and_then(Value::as_str) == self.playback.device_id.as_deref();
+
+            device["is_active"] = json!(is_active);
+        }
+
+        devices
+    }
+
+    fn get_active_device(&self) -> Option<Value> {
+        self.get_devices()
+            .into_iter()
+            .find(|device| device["is_active"] == json!(true))
+    }
+
+    fn get_playback_state(&mut self) -> Option<Value> {
+        self.advance();
+
+        if !self.playback.is_active() {
+            return None;
+        }
+
+        let track_id = self.playback.get_current_track_id()?;
+        let context = self.playback.context_uri.as_ref().map(|uri| {
+            let context_type = uri.split(':').nth(1).unwrap_or_default();
+
+            json!({
+                "type": context_type,
+                "uri": uri,
+                "href": null,
+                "external_urls": {},
+            })
+        });
+
+        Some(json!({
+            "device": self.get_active_device(),
+            "repeat_state": self.playback.repeat_state,
+            "shuffle_state": self.playback.shuffle_state,
+            "context": context,
+            "timestamp": self.playback.timestamp,
+            "progress_ms": self.playback.get_progress_ms(),
+            "is_playing": self.playback.is_playing,
+            "item": self.fixtures.track_object(&track_id),
+            "currently_playing_type": "track",
+            "actions": { "disallows": {} },
+        }))
+    }
+
+    fn paginate(
+        &self,
+        path: &str,
+        items: Vec<Value>,
+        query: &HashMap<String, String>,
+        default_limit: usize,
+    ) -> Value {
+        let total = items.len();
+        let limit = Self::get_number_param(query, "limit").unwrap_or(default_limit);
+        let offset = Self::get_number_param(query, "offset").unwrap_or(0);
+        let page: Vec<Value> = items.into_iter().skip(offset).take(limit).collect();
+
+        let next = match offset + limit < total {
+            true => Some(self.get_page_url(path, query, offset + limit, limit)),
+            false => None,
+        };
+        let previous = match offset > 0 {
+            true => Some(self.get_page_url(path, query, offset.saturating_sub(limit), limit)),
+            false => None,
+        };
+
+        json!({
+            "href": self.get_page_url(path, query, offset, limit),
+            "items": page,
+            "limit": limit,
+            "offset": offset,
+            "total": total,
+            "next": next,
+            "previous": previous,
+        })
+    }
+
+    fn get_page_url(
+        &self,
+        path: &str,
+        query: &HashMap<String, String>,
+        offset: usize,
+        limit: usize,
+    ) -> String {
+        let mut params: Vec<(String, String)> = query
+            .iter()
+            .filter(|(key, _)| key.as_str() != "offset" && key.as_str() != "limit")
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+
+        params.sort();
+        params.push(("offset".to_string(), offset.to_string()));
+        params.push(("limit".to_string(), limit.to_string()));
+
+        match Url::parse_with_params(&format!("{}{}", self.base_url, path), &params) {
+            Ok(url) => url.to_string(),
+            Err(_) => format!("{}{}", self.base_url, path),
+        }
+    }
+
+    fn get_number_param(query: &HashMap<String, String>, key: &str) -> Option<usize> {
+        query.get(key).and_then(|value| value.parse::<usize>().ok())
+    }
+}
+
+pub struct MockServer {
+    listener: TcpListener,
+    state: SharedState,
+}
+
+impl MockServer {
+    pub async fn bind(host: &str, port: u16, fixtures: Fixtures) -> AppResult<Self> {
+        let listener = TcpListener::bind((host, port)).await?;
+        let base_url = format!("http://{}", listener.local_addr()?);
+        let state = Arc::new(Mutex::new(MockState::new(fixtures, base_url)));
+
+        Ok(Self { listener, state })
+    }
+
+    pub fn local_addr(&self) -> AppResult<SocketAddr> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    pub fn router(&self) -> Router {
+        let api: Router<SharedState> = Router::new()
+            .route("/me", get(get_me))
+            .route("/me/player", get(get_player).put(transfer_playback))
+            .route("/me/player/devices", get(get_devices))
+            .route("/me/player/play", put(play))
+            .route("/me/player/pause", put(pause))
+            .route("/me/player/next", post(next))
+            .route("/me/player/previous", post(previous))
+            .route("/me/player/shuffle", put(set_shuffle))
+            .route("/me/player/repeat", put(set_repeat))
+            .route("/me/player/seek", put(seek))
+            .route("/me/player/volume", put(set_volume))
+            .route("/me/player/queue", get(get_queue).post(add_to_queue))
+            .route(
+                "/me/tracks",
+                get(get_saved_tracks)
+                    .put(save_tracks)
+                    .delete(remove_saved_tracks),
+            )
+            .route("/me/tracks/contains", get(check_saved_tracks))
+            .route(
+                "/me/albums",
+                get(get_saved_albums)
+                    .put(save_albums)
+                    .delete(remove_saved_albums),
+            )
+            .route("/me/albums/contains", get(check_saved_albums))
+            .route("/me/following", get(get_followed_artists))
+            .route("/me/playlists", get(get_playlists))
+            .route("/users/{id}/playlists", post(create_playlist))
+            .route("/playlists/{id}", get(get_playlist).put(update_playlist))
+            .route(
+                "/playlists/{id}/tracks",
+                get(get_playlist_tracks)
+                    .post(add_playlist_tracks)
+                    .put(reorder_playlist_tracks)
+                    .delete(remove_playlist_tracks),
+            )
+            .route("/tracks", get(get_tracks))
+            .route("/tracks/{id}", get(get_track))
+            .route("/albums", get(get_albums))
+            .route("/albums/{id}", get(get_album))
+            .route("/albums/{id}/tracks", get(get_album_tracks))
+            .route("/artists", get(get_artists))
+            .route("/artists/{id}", get(get_artist))
+            .route("/artists/{id}/top-tracks", get(get_artist_top_tracks))
+            .route("/artists/{id}/albums", get(get_artist_albums))
+            .route("/search", get(search))
+            .route_layer(from_fn(require_token));
+
+        Router::new()
+            .nest("/v1", api)
+            .route("/authorize", get(authorize))
+            .route("/api/token", post(token))
+            .route("/artist/{id}", get(artist_page))
+            .with_state(self.state.clone())
+            .layer(from_fn_with_state(self.state.clone(), scripted_responses))
+    }
+
+    pub async fn run(self) -> AppResult<()> {
+        let router = self.router();
+
+        serve(self.listener, router)
+            .with_graceful_shutdown(async {
+                signal::ctrl_c().await.ok();
+            })
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn json_response(status: StatusCode, value: Value) -> Response {
+    (status, Json(value)).into_response()
+}
+
+fn error_response(status: StatusCode, message: &str, reason: Option<&str>) -> Response {
+    let mut error = json!({
+        "status": status.as_u16(),
+        "message": message,
+    });
+
+    if let Some(reason) = reason {
+        error["reason"] = json!(reason);
+    }
+
+    json_response(status, json!({ "error": error }))
+}
+
+fn no_active_device_response() -> Response {
+    error_response(
+        StatusCode::NOT_FOUND,
+        "Player command failed: No active device found",
+        Some("NO_ACTIVE_DEVICE"),
+    )
+}
+
+fn not_found_response() -> Response {
+    error_response(StatusCode::NOT_FOUND, "Resource not found", None)
+}
+
+fn parse_body(body: &Bytes) -> Value {
+    serde_json::from_slice(body).unwrap_or(Value::Null)
+}
+
+fn parse_ids_from_query_or_body(query: &HashMap<String, String>, body: &Bytes) -> Vec<String> {
+    let ids = parse_ids(query);
+
+    match ids.is_empty() {
+        true => parse_body(body)["ids"]
+            .as_array()
+            .map(|ids| {
+                ids.iter()
+                    .filter_map(Value::as_str)
+                    .map(|id| id.to_string())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        false => ids,
+    }
+}
+
+fn parse_ids(query: &HashMap<String, String>) -> Vec<String> {
+    query
+        .get("ids")
+        .map(|ids| ids.split(',').map(|id| id.to_string()).collect())
+        .unwrap_or_default()
+}
+
+fn get_playlist_entry_from_uri(uri: &str) -> String {
+    match uri.starts_with("spotify:local:") {
+        true => uri.to_string(),
+        false => get_id_from_uri(uri),
+    }
+}
+
+fn get_id_from_uri(uri: &str) -> String {
+    uri.rsplit(':').next().unwrap_or_default().to_string()
+}
+
+async fn scripted_responses(
+    State(state): State<SharedState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let method = request.method().to_string();
+    let path = request.uri().path().to_string();
+
+    let scripted = {
+        let mut state = state.lock().unwrap();
+
+        match state
+            .responses
+            .iter()
+            .position(|response| response.matches(&method, &path))
+        {
+            Some(index) => {
+                let response = state.responses[index].clone();
+
+                if let Some(times) = state.responses[index].times {
+                    if times <= 1 {
+                        state.responses.remove(index);
+                    } else {
+                        state.responses[index].times = Some(times - 1);
+                    }
+                }
+
+                Some(response)
+            }
+            None => None,
+        }
+    };
+
+    match scripted {
+        Some(scripted) => {
+            let body = match &scripted.body {
+                Some(body) => Body::from(body.to_string()),
+                None => Body::empty(),
+            };
+            let mut response = Response::new(body);
+
+            *response.status_mut() =
+                StatusCode::from_u16(scripted.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+            if scripted.body.is_some() {
+                response
+                    .headers_mut()
+                    .insert("content-type", HeaderValue::from_static("application/json"));
+            }
+
+            for (key, value) in scripted.headers {
+                if let (Ok(key), Ok(value)) = (
+                    HeaderName::try_from(key.as_str()),
+                    HeaderValue::try_from(value.as_str()),
+                ) {
+                    response.headers_mut().insert(key, value);
+                }
+            }
+
+            response
+        }
+        None => next.run(request).await,
+    }
+}
+
+async fn require_token(request: Request, next: Next) -> Response {
+    let is_authorized = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.len() > "Bearer ".len() && value.starts_with("Bearer "))
+        .unwrap_or(false);
+
+    if !is_authorized {
+        return error_response(StatusCode::UNAUTHORIZED, "No token provided", None);
+    }
+
+    next.run(request).await
+}
+
+async fn authorize(State(state): State<SharedState>, Query(query): QueryParams) -> Response {
+    let redirect_uri = match query.get("redirect_uri") {
+        Some(redirect_uri) => redirect_uri,
+        None => return error_response(StatusCode::BAD_REQUEST, "Missing redirect_uri", None),
+    };
+
+    state.lock().unwrap().code_challenge = query.get("code_challenge").cloned();
+
+    let mut params = vec![("code", "mock-authorization-code".to_string())];
+
+    if let Some(state) = query.get("state") {
+        params.push(("state", state.clone()));
+    }
+
+    match Url::parse_with_params(redirect_uri, &params) {
+        Ok(url) => Redirect::to(url.as_str()).into_response(),
+        Err(_) => error_response(StatusCode::BAD_REQUEST, "Invalid redirect_uri", None),
+    }
+}
+
+async fn token(
+    State(state): State<SharedState>,
+    Form(form): Form<HashMap<String, String>>,
+) -> Response {
+    let mut state = state.lock().unwrap();
+
+    if form.get("grant_type").map(|grant_type| grant_type.as_str()) == Some("authorization_code") {
+        if let Some(code_challenge) = state.code_challenge.take() {
+            let is_verified = form
+                .get("code_verifier")
+                .map(|code_verifier| Pkce::get_code_challenge(code_verifier) == code_challenge)
+                .unwrap_or(false);
+
+            if !is_verified {
+                return json_response(
+                    StatusCode::BAD_REQUEST,
+                    json!({
+                        "error": "invalid_grant",
+                        "error_description": "code_verifier was incorrect",
+                    }),
+                );
+            }
+        }
+    }
+
+    match form.get("grant_type").map(|grant_type| grant_type.as_str()) {
+        Some("authorization_code") | Some("refresh_token") => {
+            state.issued_tokens += 1;
+
+            json_response(
+                StatusCode::OK,
+                json!({
+                    "access_token": format!("mock-access-token-{}", state.issued_tokens),
+                    "token_type": "Bearer",
+                    "scope": "",
+                    "expires_in": state.fixtures.token_expires_in,
+                    "refresh_token": format!("mock-refresh-token-{}", state.issued_tokens),
+                }),
+            )
+        }
+        _ => json_response(
+            StatusCode::BAD_REQUEST,
+            json!({
+                "error": "unsupported_grant_type",
+                "error_description": "grant_type must be authorization_code or refresh_token",
+            }),
+        ),
+    }
+}
+
+async fn artist_page(State(state): State<SharedState>, Path(id): Path<String>) -> Response {
+    let state = state.lock().unwrap();
+
+    match state.fixtures.get_artist(&id) {
+        Some(artist) => Html(format!(
+            "<!DOCTYPE html>
+            <html>
+                <body>
+                    <div id=\"main\">
+                        <h1>{}</h1>
+                        <span data-testid=\"monthly-listeners-label\">{} monthly listeners</span>
+                    </div>
+                </body>
+            </html>",
+            artist.name,
+            artist.monthly_listeners.to_formatted_string(&Locale::en)
+        ))
+        .into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn get_me(State(state): State<SharedState>) -> Response {
+    let state = state.lock().unwrap();
+
+    json_response(StatusCode::OK, state.fixtures.user.clone())
+}
+
+async fn get_player(State(state): State<SharedState>) -> Response {
+    let mut state = state.lock().unwrap();
+
+    match state.get_playback_state() {
+        Some(playback_state) => json_response(StatusCode::OK, playback_state),
+        None => StatusCode::NO_CONTENT.into_response(),
+    }
+}
+
+async fn get_devices(State(state): State<SharedState>) -> Response {
+    let state = state.lock().unwrap();
+
+    json_response(StatusCode::OK, json!({ "devices": state.get_devices() }))
+}
+
+async fn transfer_playback(State(state): State<SharedState>, body: Bytes) -> Response {
+    let mut state = state.lock().unwrap();
+    let body = parse_body(&body);
+    let device_id = body["device_ids"][0].as_str().map(|id| id.to_string());
+
+    let device_id = match device_id {
+        Some(device_id) => device_id,
+        None => return error_response(StatusCode::BAD_REQUEST, "Missing device_ids", None),
+    };
+
+    let device_exists = state
+        .devices
+        .iter()
+        .any(|device| device["id"] == json!(device_id));
+
+    if !device_exists {
+        return error_response(StatusCode::NOT_FOUND, "Device not found", None);
+    }
+
+    state.advance();
+    state.playback.device_id = Some(device_id);
+
+    if body["play"] == json!(true) {
+        state.playback.resume();
+    }
+
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn play(State(state): State<SharedState>, body: Bytes) -> Response {
+    let mut state = state.lock().unwrap();
+    let body = parse_body(&body);
+
+    if state.playback.device_id.is_none() {
+        return no_active_device_response();
+    }
+
+    state.advance();
+
+    if let Some(context_uri) = body["context_uri"].as_str() {
+        let tracks = state.fixtures.get_context_tracks(context_uri);
+
+        if tracks.is_empty() {
+            return not_found_response();
+        }
+
+        let index = match (
+            body["offset"]["position"].as_u64(),
+            body["offset"]["uri"].as_str(),
+        ) {
+            (Some(position), _) => position as usize,
+            (None, Some(uri)) => {
+                let track_id = get_id_from_uri(uri);
+
+                tracks.iter().position(|id| *id == track_id).unwrap_or(0)
+            }
+            (None, None) => 0,
+        };
+
+        state
+            .playback
+            .play_tracks(Some(context_uri.to_string()), tracks, index);
+    } else if let Some(uris) = body["uris"].as_array() {
+        let tracks: Vec<String> = uris
+            .iter()
+            .filter_map(Value::as_str)
+            .map(get_id_from_uri)
+            .filter(|id| state.fixtures.get_track(id).is_some())
+            .collect();
+
+        if tracks.is_empty() {
+            return not_found_response();
+        }
+
+        let index = body["offset"]["position"].as_u64().unwrap_or(0) as usize;
+
+        state.playback.play_tracks(None, tracks, index);
+    } else {
+        if !state.playback.is_active() {
+            return no_active_device_response();
+        }
+
+        state.playback.resume();
+    }
+
+    if let Some(position_ms) = body["position_ms"].as_u64() {
+        state.playback.seek(position_ms);
+    }
+
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn pause(State(state): State<SharedState>) -> Response {
+    let mut state = state.lock().unwrap();
+
+    if !state.playback.is_active() {
+        return no_active_device_response();
+    }
+
+    state.advance();
+    state.playback.pause();
+
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn next(State(state): State<SharedState>) -> Response {
+    let mut state = state.lock().unwrap();
+
+    if !state.playback.is_active() {
+        return no_active_device_response();
+    }
+
+    state.advance();
+    state.playback.next();
+
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn previous(State(state): State<SharedState>) -> Response {
+    let mut state = state.lock().unwrap();
+
+    if !state.playback.is_active() {
+        return no_active_device_response();
+    }
+
+    state.advance();
+    state.playback.previous();
+
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn set_shuffle(State(state): State<SharedState>, Query(query): QueryParams) -> Response {
+    let mut state = state.lock().unwrap();
+
+    if !state.playback.is_active() {
+        return no_active_device_response();
+    }
+
+    match query.get("state").map(|value| value.as_str()) {
+        Some("true") => state.playback.shuffle_state = true,
+        Some("false") => state.playback.shuffle_state = false,
+        _ => return error_response(StatusCode::BAD_REQUEST, "Invalid state", None),
+    }
+
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn set_repeat(State(state): State<SharedState>, Query(query): QueryParams) -> Response {
+    let mut state = state.lock().unwrap();
+
+    if !state.playback.is_active() {
+        return no_active_device_response();
+    }
+
+    match query.get("state").map(|value| value.as_str()) {
+        Some(repeat_state @ ("off" | "context" | "track")) => {
+            state.playback.repeat_state = repeat_state.to_string();
+        }
+        _ => return error_response(StatusCode::BAD_REQUEST, "Invalid state", None),
+    }
+
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn seek(State(state): State<SharedState>, Query(query): QueryParams) -> Response {
+    let mut state = state.lock().unwrap();
+
+    if !state.playback.is_active() {
+        return no_active_device_response();
+    }
+
+    let position_ms = match query
+        .get("position_ms")
+        .and_then(|value| value.parse().ok())
+    {
+        Some(position_ms) => position_ms,
+        None => return error_response(StatusCode::BAD_REQUEST, "Invalid position_ms", None),
+    };
+
+    state.playback.seek(position_ms);
+    state.advance();
+
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn set_volume(State(state): State<SharedState>, Query(query): QueryParams) -> Response {
+    let mut state = state.lock().unwrap();
+
+    let device_id = match &state.playback.device_id {
+        Some(device_id) => device_id.clone(),
+        None => return no_active_device_response(),
+    };
+
+    let volume = match query
+        .get("volume_percent")
+        .and_then(|value| value.parse::<u64>().ok())
+    {
+        Some(volume) if volume <= 100 => volume,
+        _ => return error_response(StatusCode::BAD_REQUEST, "Invalid volume_percent", None),
+    };
+
+    for device in state.devices.iter_mut() {
+        if device["id"] == json!(device_id) {
+            device["volume_percent"] = json!(volume);
+        }
+    }
+
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn get_queue(State(state): State<SharedState>) -> Response {
+    let mut state = state.lock().unwrap();
+
+    state.advance();
+
+    let currently_playing = state
+        .playback
+        .get_current_track_id()
+        .and_then(|id| state.fixtures.track_object(&id));
+    let queue: Vec<Value> = state
+        .playback
+        .queue
+        .iter()
+        .chain(state.playback.tracks.iter().skip(state.playback.index + 1))
+        .filter_map(|id| state.fixtures.track_object(id))
+        .collect();
+
+    json_response(
+        StatusCode::OK,
+        json!({
+            "currently_playing": currently_playing,
+            "queue": queue,
+        }),
+    )
+}
+
+async fn add_to_queue(State(state): State<SharedState>, Query(query): QueryParams) -> Response {
+    let mut state = state.lock().unwrap();
+
+    if !state.playback.is_active() {
+        return no_active_device_response();
+    }
+
+    let track_id = match query.get("uri") {
+        Some(uri) => get_id_from_uri(uri),
+        None => return error_response(StatusCode::BAD_REQUEST, "Missing uri", None),
+    };
+
+    if state.fixtures.get_track(&track_id).is_none() {
+        return not_found_response();
+    }
+
+    state.playback.queue.push(track_id);
+
+    StatusCode::NO_CONTENT.into_response()
+}
+
+async fn get_track(State(state): State<SharedState>, Path(id): Path<String>) -> Response {
+    let state = state.lock().unwrap();
+
+    match state.fixtures.track_object(&id) {
+        Some(track) => json_response(StatusCode::OK, track),
+        None => not_found_response(),
+    }
+}
+
+async fn get_tracks(State(state): State<SharedState>, Query(query): QueryParams) -> Response {
+    let state = state.lock().unwrap();
+    let tracks: Vec<Value> = parse_ids(&query)
+        .iter()
+        .map(|id| state.fixtures.track_object(id).unwrap_or(Value::Null))
+        .collect();
+
+    json_response(StatusCode::OK, json!({ "tracks": tracks }))
+}
+
+async fn get_saved_tracks(State(state): State<SharedState>, Query(query): QueryParams) -> Response {
+    let state = state.lock().unwrap();
+    let tracks: Vec<Value> = state
+        .fixtures
+        .saved_tracks
+        .iter()
+        .filter_map(|id| state.fixtures.track_object(id))
+        .map(|track| json!({ "added_at": MOCK_ADDED_AT, "track": track }))
+        .collect();
+
+    json_response(
+        StatusCode::OK,
+        state.paginate("/v1/me/tracks", tracks, &query, 20),
+    )
+}
+
+async fn check_saved_tracks(
+    State(state): State<SharedState>,
+    Query(query): QueryParams,
+) -> Response {
+    let state = state.lock().unwrap();
+    let saved: Vec<bool> = parse_ids(&query)
+        .iter()
+        .map(|id| state.fixtures.saved_tracks.contains(id))
+        .collect();
+
+    json_response(StatusCode::OK, json!(saved))
+}
+
+async fn save_tracks(
+    State(state): State<SharedState>,
+    Query(query): QueryParams,
+    body: Bytes,
+) -> Response {
+    let mut state = state.lock().unwrap();
+
+    for id in parse_ids_from_query_or_body(&query, &body) {
+        if !state.fixtures.saved_tracks.contains(&id) {
+            state.fixtures.saved_tracks.insert(0, id);
+        }
+    }
+
+    StatusCode::OK.into_response()
+}
+
+async fn remove_saved_tracks(
+    State(state): State<SharedState>,
+    Query(query): QueryParams,
+    body: Bytes,
+) -> Response {
+    let mut state = state.lock().unwrap();
+    let ids = parse_ids_from_query_or_body(&query, &body);
+
+    state.fixtures.saved_tracks.retain(|id| !ids.contains(id));
+
+    StatusCode::OK.into_response()
+}
+
+async fn get_saved_albums(State(state): State<SharedState>, Query(query): QueryParams) -> Response {
+    let state = state.lock().unwrap();
+    let albums: Vec<Value> = state
+        .fixtures
+        .saved_albums
+        .iter()
+        .filter_map(|id| get_album_object(&state, id, &HashMap::new()))
+        .map(|album| json!({ "added_at": MOCK_ADDED_AT, "album": album }))
+        .collect();
+
+    json_response(
+        StatusCode::OK,
+        state.paginate("/v1/me/albums", albums, &query, 20),
+    )
+}
+
+async fn check_saved_albums(
+    State(state): State<SharedState>,
+    Query(query): QueryParams,
+) -> Response {
+    let state = state.lock().unwrap();
+    let saved: Vec<bool> = parse_ids(&query)
+        .iter()
+        .map(|id| state.fixtures.saved_albums.contains(id))
+        .collect();
+
+    json_response(StatusCode::OK, json!(saved))
+}
+
+async fn save_albums(
+    State(state): State<SharedState>,
+    Query(query): QueryParams,
+    body: Bytes,
+) -> Response {
+    let mut state = state.lock().unwrap();
+
+    for id in parse_ids_from_query_or_body(&query, &body) {
+        if !state.fixtures.saved_albums.contains(&id) {
+            state.fixtures.saved_albums.insert(0, id);
+        }
+    }
+
+    StatusCode::OK.into_response()
+}
+
+async fn remove_saved_albums(
+    State(state): State<SharedState>,
+    Query(query): QueryParams,
+    body: Bytes,
+) -> Response {
+    let mut state = state.lock().unwrap();
+    let ids = parse_ids_from_query_or_body(&query, &body);
+
+    state.fixtures.saved_albums.retain(|id| !ids.contains(id));
+
+    StatusCode::OK.into_response()
+}
+
+async fn get_followed_artists(
+    State(state): State<SharedState>,
+    Query(query): QueryParams,
+) -> Response {
+    let state = state.lock().unwrap();
+
+    if query.get("type").map(|value| value.as_str()) != Some("artist") {
+        return error_response(StatusCode::BAD_REQUEST, "Invalid type parameter", None);
+    }
+
+    let followed = &state.fixtures.followed_artists;
+    let limit = MockState::get_number_param(&query, "limit").unwrap_or(20);
+    let start = match query.get("after") {
+        Some(after) => match followed.iter().position(|id| id == after) {
+            Some(position) => position + 1,
+            None => return error_response(StatusCode::BAD_REQUEST, "Invalid after cursor", None),
+        },
+        None => 0,
+    };
+    let page: Vec<String> = followed.iter().skip(start).take(limit).cloned().collect();
+    let artists: Vec<Value> = page
+        .iter()
+        .filter_map(|id| state.fixtures.artist_object(id))
+        .collect();
+    let after = match start + limit < followed.len() {
+        true => page.last().cloned(),
+        false => None,
+    };
+    let next = after.as_ref().map(|after| {
+        format!(
+            "{}/v1/me/following?type=artist&after={}&limit={}",
+            state.base_url, after, limit
+        )
+    });
+
+    json_response(
+        StatusCode::OK,
+        json!({
+            "artists": {
+                "href": format!("{}/v1/me/following?type=artist", state.base_url),
+                "items": artists,
+                "limit": limit,
+                "next": next,
+                "cursors": { "after": after },
+                "total": followed.len(),
+            }
+        }),
+    )
+}
+
+async fn get_playlists(State(state): State<SharedState>, Query(query): QueryParams) -> Response {
+    let state = state.lock().unwrap();
+    let playlists: Vec<Value> = state
+        .fixtures
+        .playlists
+        .iter()
+        .map(|playlist| {
+            state
+                .fixtures
+                .simplified_playlist(playlist, &state.base_url)
+        })
+        .collect();
+
+    json_response(
+        StatusCode::OK,
+        state.paginate("/v1/me/playlists", playlists, &query, 20),
+    )
+}
+
+fn get_playlist_tracks_page(
+    state: &MockState,
+    id: &str,
+    query: &HashMap<String, String>,
+    default_limit: usize,
+) -> Option<Value> {
+    let playlist = state.fixtures.get_playlist(id)?;
+    let tracks: Vec<Value> = playlist
+        .tracks
+        .iter()
+        .map(|entry| match state.fixtures.local_track(entry) {
+            Some(track) => json!({ "added_at": MOCK_ADDED_AT, "is_local": true, "track": track }),
+            None => json!({
+                "added_at": MOCK_ADDED_AT,
+                "is_local": false,
+                "track": state.fixtures.track_object(entry),
+            }),
+        })
+        .collect();
+    let path = format!("/v1/playlists/{}/tracks", id);
+
+    Some(state.paginate(&path, tracks, query, default_limit))
+}
+
+async fn get_playlist(State(state): State<SharedState>, Path(id): Path<String>) -> Response {
+    let state = state.lock().unwrap();
+
+    let playlist = match state.fixtures.get_playlist(&id) {
+        Some(playlist) => playlist,
+        None => return not_found_response(),
+    };
+    let tracks = get_playlist_tracks_page(&state, &id, &HashMap::new(), 100).unwrap_or(Value::Null);
+
+    json_response(
+        StatusCode::OK,
+        state
+            .fixtures
+            .playlist_object(playlist, &state.base_url, tracks),
+    )
+}
+
+async fn create_playlist(
+    State(state): State<SharedState>,
+    Path(user_id): Path<String>,
+    body: Bytes,
+) -> Response {
+    let mut state = state.lock().unwrap();
+    let body = parse_body(&body);
+
+    if state.fixtures.user["id"].as_str() != Some(user_id.as_str()) {
+        return error_response(
+            StatusCode::FORBIDDEN,
+            "You cannot create a playlist for another user",
+            None,
+        );
+    }
+
+    let name = match body["name"].as_str() {
+        Some(name) => name.to_string(),
+        None => return error_response(StatusCode::BAD_REQUEST, "Missing name", None),
+    };
+    let playlist = FixturePlaylist {
+        id: format!("mockplaylistnew{:06}", state.fixtures.playlists.len() + 1),
+        name,
+        description: body["description"].as_str().unwrap_or_default().to_string(),
+        owner: user_id,
+        public: body["public"].as_bool().unwrap_or(true),
+        collaborative: body["collaborative"].as_bool().unwrap_or(false),
+        followers: 0,
+        tracks: vec![],
+        snapshot: 0,
+    };
+    let tracks = state.paginate(
+        &format!("/v1/playlists/{}/tracks", playlist.id),
+        vec![],
+        &HashMap::new(),
+        100,
+    );
+    let object = state
+        .fixtures
+        .playlist_object(&playlist, &state.base_url, tracks);
+
+    state.fixtures.playlists.push(playlist);
+
+    json_response(StatusCode::CREATED, object)
+}
+
+fn check_playlist_owner(state: &MockState, id: &str) -> Option<Response> {
+    let user_id = state.fixtures.user["id"].as_str().unwrap_or_default();
+
+    match state.fixtures.get_playlist(id) {
+        Some(playlist) if playlist.owner == user_id || playlist.collaborative => None,
+        Some(_) => Some(error_response(
+            StatusCode::FORBIDDEN,
+            "You cannot modify a playlist you do not own",
+            None,
+        )),
+        None => Some(not_found_response()),
+    }
+}
+
+fn check_snapshot(playlist: &FixturePlaylist, body: &Value) -> Option<Response> {
+    match body["snapshot_id"].as_str() {
+        Some(snapshot_id) if snapshot_id != playlist.get_snapshot_id() => Some(error_response(
+            StatusCode::BAD_REQUEST,
+            "Invalid snapshot id",
+            None,
+        )),
+        _ => None,
+    }
+}
+
+async fn update_playlist(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+    body: Bytes,
+) -> Response {
+    let mut state = state.lock().unwrap();
+    let body = parse_body(&body);
+
+    if let Some(response) = check_playlist_owner(&state, &id) {
+        return response;
+    }
+
+    let playlist = match state.fixtures.get_playlist_mut(&id) {
+        Some(playlist) => playlist,
+        None => return not_found_response(),
+    };
+
+    if let Some(name) = body["name"].as_str() {
+        playlist.name = name.to_string();
+    }
+
+    if let Some(description) = body["description"].as_str() {
+        playlist.description = description.to_string();
+    }
+
+    if let Some(public) = body["public"].as_bool() {
+        playlist.public = public;
+    }
+
+    if let Some(collaborative) = body["collaborative"].as_bool() {
+        playlist.collaborative = collaborative;
+    }
+
+    StatusCode::OK.into_response()
+}
+
+async fn add_playlist_tracks(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+    Query(query): QueryParams,
+    body: Bytes,
+) -> Response {
+    let mut state = state.lock().unwrap();
+    let body = parse_body(&body);
+
+    let mut entries: Vec<String> = match body["uris"].as_array() {
+        Some(uris) => uris
+            .iter()
+            .filter_map(Value::as_str)
+            .map(get_playlist_entry_from_uri)
+            .collect(),
+        None => query
+            .get("uris")
+            .map(|uris| uris.split(',').map(get_playlist_entry_from_uri).collect())
+            .unwrap_or_default(),
+    };
+
+    if entries.is_empty() {
+        return error_response(StatusCode::BAD_REQUEST, "No uris provided", None);
+    }
+
+    if let Some(response) = check_playlist_owner(&state, &id) {
+        return response;
+    }
+
+    let playlist = match state.fixtures.get_playlist_mut(&id) {
+        Some(playlist) => playlist,
+        None => return not_found_response(),
+    };
+    let position = body["position"]
+        .as_u64()
+        .map(|position| (position as usize).min(playlist.tracks.len()))
+        .unwrap_or(playlist.tracks.len());
+
+    let tail = playlist.tracks.split_off(position);
+
+    playlist.tracks.append(&mut entries);
+    playlist.tracks.extend(tail);
+    playlist.snapshot += 1;
+
+    json_response(
+        StatusCode::CREATED,
+        json!({ "snapshot_id": playlist.get_snapshot_id() }),
+    )
+}
+
+async fn remove_playlist_tracks(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+    body: Bytes,
+) -> Response {
+    let mut state = state.lock().unwrap();
+    let body = parse_body(&body);
+
+    if let Some(response) = check_playlist_owner(&state, &id) {
+        return response;
+    }
+
+    let playlist = match state.fixtures.get_playlist_mut(&id) {
+        Some(playlist) => playlist,
+        None => return not_found_response(),
+    };
+
+    if let Some(response) = check_snapshot(playlist, &body) {
+        return response;
+    }
+
+    let entries: Vec<String> = body["tracks"]
+        .as_array()
+        .map(|tracks| {
+            tracks
+                .iter()
+                .filter_map(|track| track["uri"].as_str())
+                .map(get_playlist_entry_from_uri)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if entries.is_empty() {
+        return error_response(StatusCode::BAD_REQUEST, "No tracks provided", None);
+    }
+
+    playlist.tracks.retain(|entry| !entries.contains(entry));
+    playlist.snapshot += 1;
+
+    json_response(
+        StatusCode::OK,
+        json!({ "snapshot_id": playlist.get_snapshot_id() }),
+    )
+}
+
+async fn reorder_playlist_tracks(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+    body: Bytes,
+) -> Response {
+    let mut state = state.lock().unwrap();
+    let body = parse_body(&body);
+
+    if let Some(response) = check_playlist_owner(&state, &id) {
+        return response;
+    }
+
+    let playlist = match state.fixtures.get_playlist_mut(&id) {
+        Some(playlist) => playlist,
+        None => return not_found_response(),
+    };
+
+    if let Some(response) = check_snapshot(playlist, &body) {
+        return response;
+    }
+
+    if let Some(uris) = body["uris"].as_array() {
+        playlist.tracks = uris
+            .iter()
+            .filter_map(Value::as_str)
+            .map(get_playlist_entry_from_uri)
+            .collect();
+    } else {
+        let total = playlist.tracks.len();
+        let range_start = body["range_start"].as_u64().unwrap_or(0) as usize;
+        let range_length = body["range_length"].as_u64().unwrap_or(1) as usize;
+        let insert_before = body["insert_before"].as_u64().unwrap_or(0) as usize;
+
+        if range_start + range_length > total || insert_before > total {
+            return error_response(StatusCode::BAD_REQUEST, "Index out of bounds", None);
+        }
+
+        let moved: Vec<String> = playlist
+            .tracks
+            .drain(range_start..range_start + range_length)
+            .collect();
+        let insert_at = match insert_before > range_start {
+            true => insert_before.saturating_sub(range_length).max(range_start),
+            false => insert_before,
+        };
+
+        for (offset, entry) in moved.into_iter().enumerate() {
+            playlist.tracks.insert(insert_at + offset, entry);
+        }
+    }
+
+    playlist.snapshot += 1;
+
+    json_response(
+        StatusCode::OK,
+        json!({ "snapshot_id": playlist.get_snapshot_id() }),
+    )
+}
+
+async fn get_playlist_tracks(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+    Query(query): QueryParams,
+) -> Response {
+    let state = state.lock().unwrap();
+
+    match get_playlist_tracks_page(&state, &id, &query, 100) {
+        Some(tracks) => json_response(StatusCode::OK, tracks),
+        None => not_found_response(),
+    }
+}
+
+fn get_album_object(state: &MockState, id: &str, query: &HashMap<String, String>) -> Option<Value> {
+    let album = state.fixtures.get_album(id)?;
+    let tracks: Vec<Value> = album
+        .tracks
+        .iter()
+        .filter_map(|track_id| state.fixtures.simplified_track(track_id))
+        .collect();
+    let path = format!("/v1/albums/{}/tracks", id);
+    let tracks = state.paginate(&path, tracks, query, 50);
+
+    state.fixtures.album_object(id, tracks)
+}
+
+async fn get_album(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+    Query(query): QueryParams,
+) -> Response {
+    let state = state.lock().unwrap();
+    let query: HashMap<String, String> = query
+        .into_iter()
+        .filter(|(key, _)| key == "market")
+        .collect();
+
+    match get_album_object(&state, &id, &query) {
+        Some(album) => json_response(StatusCode::OK, album),
+        None => not_found_response(),
+    }
+}
+
+async fn get_album_tracks(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+    Query(query): QueryParams,
+) -> Response {
+    let state = state.lock().unwrap();
+
+    let album = match state.fixtures.get_album(&id) {
+        Some(album) => album,
+        None => return not_found_response(),
+    };
+
+    let tracks: Vec<Value> = album
+        .tracks
+        .iter()
+        .filter_map(|track_id| state.fixtures.simplified_track(track_id))
+        .collect();
+    let path = format!("/v1/albums/{}/tracks", id);
+
+    json_response(StatusCode::OK, state.paginate(&path, tracks, &query, 20))
+}
+
+async fn get_albums(State(state): State<SharedState>, Query(query): QueryParams) -> Response {
+    let state = state.lock().unwrap();
+    let albums: Vec<Value> = parse_ids(&query)
+        .iter()
+        .map(|id| get_album_object(&state, id, &HashMap::new()).unwrap_or(Value::Null))
+        .collect();
+
+    json_response(StatusCode::OK, json!({ "albums": albums }))
+}
+
+async fn get_artist(State(state): State<SharedState>, Path(id): Path<String>) -> Response {
+    let state = state.lock().unwrap();
+
+    match state.fixtures.artist_object(&id) {
+        Some(artist) => json_response(StatusCode::OK, artist),
+        None => not_found_response(),
+    }
+}
+
+async fn get_artists(State(state): State<SharedState>, Query(query): QueryParams) -> Response {
+    let state = state.lock().unwrap();
+    let artists: Vec<Value> = parse_ids(&query)
+        .iter()
+        .map(|id| state.fixtures.artist_object(id).unwrap_or(Value::Null))
+        .collect();
+
+    json_response(StatusCode::OK, json!({ "artists": artists }))
+}
+
+async fn get_artist_top_tracks(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+) -> Response {
+    let state = state.lock().unwrap();
+
+    match state.fixtures.get_artist(&id) {
+        Some(artist) => {
+            let tracks: Vec<Value> = artist
+                .top_tracks
+                .iter()
+                .filter_map(|track_id| state.fixtures.track_object(track_id))
+                .collect();
+
+            json_response(StatusCode::OK, json!({ "tracks": tracks }))
+        }
+        None => not_found_response(),
+    }
+}
+
+async fn get_artist_albums(
+    State(state): State<SharedState>,
+    Path(id): Path<String>,
+    Query(query): QueryParams,
+) -> Response {
+    let state = state.lock().unwrap();
+
+    if state.fixtures.get_artist(&id).is_none() {
+        return not_found_response();
+    }
+
+    let include_groups: Vec<String> = query
+        .get("include_groups")
+        .map(|groups| groups.split(',').map(|group| group.to_string()).collect())
+        .unwrap_or_default();
+    let albums: Vec<Value> = state
+        .fixtures
+        .albums
+        .iter()
+        .filter(|album| album.artists.contains(&id))
+        .filter(|album| include_groups.is_empty() || include_groups.contains(&album.album_type))
+        .filter_map(|album| state.fixtures.simplified_album(&album.id))
+        .collect();
+    let path = format!("/v1/artists/{}/albums", id);
+
+    json_response(StatusCode::OK, state.paginate(&path, albums, &query, 20))
+}
+
+async fn search(State(state): State<SharedState>, Query(query): QueryParams) -> Response {
+    let state = state.lock().unwrap();
+
+    let search_query = match query.get("q") {
+        Some(search_query) if !search_query.trim().is_empty() => search_query.to_lowercase(),
+        _ => return error_response(StatusCode::BAD_REQUEST, "No search query", None),
+    };
+    let search_types: Vec<String> = query
+        .get("type")
+        .map(|types| {
+            types
+                .split(',')
+                .map(|search_type| search_type.to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if search_types.is_empty() {
+        return error_response(StatusCode::BAD_REQUEST, "Missing parameter type", None);
+    }
+
+    let matches = |name: &str| name.to_lowercase().contains(&search_query);
+    let mut response = json!({});
+
+    for search_type in search_types {
+        let items: Vec<Value> = match search_type.as_str() {
+            "track" => state
+                .fixtures
+                .tracks
+                .iter()
+                .filter(|track| matches(&track.name))
+                .filter_map(|track| state.fixtures.track_object(&track.id))
+                .collect(),
+            "album" => state
+                .fixtures
+                .albums
+                .iter()
+                .filter(|album| matches(&album.name))
+                .filter_map(|album| state.fixtures.simplified_album(&album.id))
+                .collect(),
+            "artist" => state
+                .fixtures
+                .artists
+                .iter()
+                .filter(|artist| matches(&artist.name))
+                .filter_map(|artist| state.fixtures.artist_object(&artist.id))
+                .collect(),
+            "playlist" => state
+                .fixtures
+                .playlists
+                .iter()
+                .filter(|playlist| matches(&playlist.name))
+                .map(|playlist| {
+                    state
+                        .fixtures
+                        .simplified_playlist(playlist, &state.base_url)
+                })
+                .collect(),
+            _ => {
+                let message = format!("Unsupported search type {}", search_type);
+
+                return error_response(StatusCode::BAD_REQUEST, &message, None);
+            }
+        };
+        let key = format!("{}s", search_type);
+
+        response[key] = state.paginate("/v1/search", items, &query, 20);
+    }
+
+    json_response(StatusCode::OK, response)
+}
@@ -0,0 +1,112 @@
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Constraint, Layout},
+    style::Color,
+    Frame,
+};
+
+use crate::{
+    components::{list::List, loading::Loading, screen_block::ScreenBlock, Component},
+    core::{
+        app::{App, AppResult},
+        message::Message,
+        spotify::library::{Library, LibraryType},
+    },
+    widgets::{block::create_block, paragraph::create_centered_paragraph},
+};
+
+use super::{Screen, ScreenType};
+
+#[derive(Clone)]
+pub struct AddToPlaylistScreen {
+    song_id: String,
+    library: Library,
+    list: List,
+}
+
+impl AddToPlaylistScreen {
+    pub fn new(song_id: String) -> Self {
+        Self {
+            song_id,
+            library: Library::new(LibraryType::Playlists),
+            list: List::default(),
+        }
+    }
+}
+
+impl Screen for AddToPlaylistScreen {
+    fn get_screen_type(&self) -> ScreenType {
+        ScreenType::AddToPlaylistScreen
+    }
+}
+
+impl Component for AddToPlaylistScreen {
+    fn view(&mut self, app: &App, frame: &mut Frame) {
+        ScreenBlock::new_with_color("Add to Playlist", Color::Green).view(app, frame);
+
+        if !self.library.is_loaded {
+            Loading::default().view(app, frame);
+            return;
+        }
+
+        let chunks = Layout::default()
+            .margin(5)
+            .constraints(vec![Constraint::Min(1)])
+            .split(frame.area());
+
+        frame.render_widget(create_block(Color::Green), chunks[0]);
+
+        if self.list.items.is_empty() {
+            let paragraph = create_centered_paragraph(
+                "No playlists in your library, press n on the Library screen to create one",
+                Some(Color::Green),
+            );
+
+            frame.render_widget(paragraph, chunks[0]);
+            return;
+        }
+
+        self.list.set_area(chunks[0]);
+        self.list.view(app, frame);
+    }
+
+    fn tick(&mut self, _: &mut App) -> AppResult<Option<Message>> {
+        if !self.library.is_loaded {
+            return Ok(Some(Message::RefreshLibrary));
+        }
+
+        if self.library.items != self.list.items {
+            self.list = List::new(self.library.items.clone(), None);
+        }
+
+        if !self.list.items.is_empty()
+            && self.list.active_index + 1 >= self.list.items.len()
+            && self.library.has_next_page()
+        {
+            return Ok(Some(Message::NextLibraryPage));
+        }
+
+        Ok(None)
+    }
+
+    fn handle_key_press(&mut self, app: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
+        if let Some(message) = self.list.handle_key_press(app, key)? {
+            return Ok(Some(message));
+        }
+
+        if key.code != KeyCode::Enter || self.list.items.is_empty() {
+            return Ok(None);
+        }
+
+        let (_, playlist_id) = self.list.get_active_item();
+
+        Ok(Some(Message::AddToPlaylist {
+            playlist_id,
+            song_id: self.song_id.clone(),
+        }))
+    }
+
+    fn get_library(&mut self) -> Option<&mut Library> {
+        Some(&mut self.library)
+    }
+}
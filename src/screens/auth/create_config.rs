@@ -28,6 +28,7 @@ use super::show_link::ShowAuthLinkScreen;
 #[derive(Clone)]
 pub struct CreateConfigFormScreen {
     form: Form,
+    config: Config,
 }
 
 impl CreateConfigFormScreen {
@@ -40,8 +41,11 @@ impl CreateConfigFormScreen {
         let scope_placeholder = config.scope.clone().unwrap_or_default();
 
         let client_id_input = TextInput::new("Client ID", &client_id_placeholder, false);
-        let client_secret_input =
-            TextInput::new("Client Secret", &client_secret_placeholder, false);
+        let client_secret_input = TextInput::new(
+            "Client Secret (optional)",
+            &client_secret_placeholder,
+            false,
+        );
         let redirect_uri_input = TextInput::new("Redirect URI", &redirect_uri_placeholder, false);
         let scope_input = TextInput::new("Scope", &scope_placeholder, false);
 
@@ -52,7 +56,10 @@ impl CreateConfigFormScreen {
 
         let form = Form::new(inputs);
 
-        Self { form }
+        Self {
+            form,
+            config: config.clone(),
+        }
     }
 
     fn get_client_id(&self) -> String {
@@ -76,7 +83,7 @@ impl Default for CreateConfigFormScreen {
     fn default() -> Self {
         let mut inputs = Vec::<Box<dyn Input>>::new();
         let client_id_input = TextInput::new("Client ID", "", false);
-        let client_secret_input = TextInput::new("Client Secret", "", false);
+        let client_secret_input = TextInput::new("Client Secret (optional)", "", false);
         let redirect_uri_input = TextInput::new("Redirect URI", "", false);
         let scope_input = TextInput::new("Scope", "", false);
 
@@ -87,7 +94,10 @@ impl Default for CreateConfigFormScreen {
 
         let form = Form::new(inputs);
 
-        Self { form }
+        Self {
+            form,
+            config: Config::default(),
+        }
     }
 }
 
@@ -140,11 +150,16 @@ impl Component for CreateConfigFormScreen {
         match key.code {
             KeyCode::Enter => {
                 if self.form.mode == FormMode::Normal {
+                    let client_secret = self.get_client_secret();
                     let new_config = Config {
                         client_id: Some(self.get_client_id()),
-                        client_secret: Some(self.get_client_secret()),
+                        client_secret: match client_secret.trim().is_empty() {
+                            true => None,
+                            false => Some(client_secret),
+                        },
                         redirect_uri: Some(self.get_redirect_uri()),
                         scope: Some(self.get_scope()),
+                        ..self.config.clone()
                     };
 
                     let mut spotify_client = SpotifyClient::new(new_config.clone())?;
@@ -53,7 +53,12 @@ impl Component for ShowAuthLinkScreen {
         let message_chunks = Layout::default()
             .margin(2)
             .direction(Direction::Vertical)
-            .constraints([Constraint::Max(5), Constraint::Max(5), Constraint::Min(10)])
+            .constraints([
+                Constraint::Max(5),
+                Constraint::Max(5),
+                Constraint::Max(3),
+                Constraint::Min(10),
+            ])
             .split(message_area);
         let text = "In order to use the app you will need to log into your Spotify account.\n
             You can log in by navigating to the following link in your browser:\n
@@ -65,10 +70,16 @@ impl Component for ShowAuthLinkScreen {
             link = create_centered_paragraph("Link Copied to Clipboard!", Some(Color::Green));
         }
 
+        let hint = create_centered_paragraph(
+            "The app will continue automatically once you have logged in.\nIf you are logging in from another machine, choose \"Enter Code\" instead.",
+            Some(Color::Gray),
+        );
+
         frame.render_widget(paragraph, message_chunks[0]);
         frame.render_widget(link, message_chunks[1]);
+        frame.render_widget(hint, message_chunks[2]);
 
-        self.menu.area = Some(message_chunks[2]);
+        self.menu.area = Some(message_chunks[3]);
         self.menu.view(app, frame);
     }
 
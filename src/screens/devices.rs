@@ -53,7 +53,7 @@ impl Component for DevicesScreen {
     fn tick(&mut self, app: &mut App) -> AppResult<Option<Message>> {
         match app.spotify_client.clone() {
             Some(spotify_client) => {
-                if spotify_client.credentials.is_none() {
+                if !spotify_client.has_credentials() {
                     let new_screen = Box::new(ShowAuthLinkScreen::new(spotify_client.auth_url));
 
                     return Ok(Some(Message::ChangeScreen { new_screen }));
@@ -98,6 +98,10 @@ impl Component for DevicesScreen {
         match key.code {
             KeyCode::Char('r') => Ok(Some(Message::RefreshDevice)),
             KeyCode::Enter => {
+                if self.menu.menu_items.is_empty() {
+                    return Ok(None);
+                }
+
                 let name = self.menu.get_current_item();
 
                 if let Some(current_device_name) = &self.device.current_device_name {
@@ -1,59 +1,156 @@
 use ratatui::{
     crossterm::event::{KeyCode, KeyEvent},
+    layout::{Constraint, Direction, Layout},
     style::Color,
     Frame,
 };
 
 use crate::{
-    components::{prompt::Prompt, Component},
+    components::{menu::Menu, screen_block::ScreenBlock, Component},
     core::{
         app::{App, AppResult},
         message::Message,
+        spotify::error::SpotifyError,
     },
+    layout::rect::get_centered_rect,
+    widgets::paragraph::create_centered_paragraph,
 };
 
-use super::{Screen, ScreenType};
+use super::{devices::DevicesScreen, Screen, ScreenType};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ErrorAction {
+    RetryNow,
+    GoBack,
+    PickDevice,
+    LogIn,
+    Quit,
+}
+
+impl ErrorAction {
+    pub fn get_label(&self) -> String {
+        match self {
+            ErrorAction::RetryNow => "Retry Now".to_string(),
+            ErrorAction::GoBack => "Go Back".to_string(),
+            ErrorAction::PickDevice => "Pick A Device".to_string(),
+            ErrorAction::LogIn => "Log In Again".to_string(),
+            ErrorAction::Quit => "Quit".to_string(),
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct ErrorScreen {
     message: Option<String>,
+    actions: Vec<ErrorAction>,
+    menu: Menu,
+    is_retryable: bool,
+    retry_message: Option<Message>,
 }
 
 impl Default for ErrorScreen {
     fn default() -> Self {
-        Self { message: None }
+        Self::new_with_actions(None, vec![ErrorAction::GoBack, ErrorAction::Quit])
     }
 }
 
 impl ErrorScreen {
     pub fn new(message: impl Into<String>) -> Self {
+        Self::new_with_actions(
+            Some(message.into()),
+            vec![ErrorAction::GoBack, ErrorAction::Quit],
+        )
+    }
+
+    pub fn from_spotify_error(error: &SpotifyError) -> Self {
+        let actions = match error {
+            SpotifyError::NoActiveDevice => vec![
+                ErrorAction::PickDevice,
+                ErrorAction::GoBack,
+                ErrorAction::Quit,
+            ],
+            SpotifyError::MissingScope { .. } | SpotifyError::AuthRevoked => {
+                vec![ErrorAction::LogIn, ErrorAction::GoBack, ErrorAction::Quit]
+            }
+            _ => vec![ErrorAction::GoBack, ErrorAction::Quit],
+        };
+        let is_retryable = !matches!(
+            error,
+            SpotifyError::MissingScope { .. }
+                | SpotifyError::AuthRevoked
+                | SpotifyError::PremiumRequired
+                | SpotifyError::NotFound { .. }
+        );
+
+        Self {
+            is_retryable,
+            ..Self::new_with_actions(Some(error.to_string()), actions)
+        }
+    }
+
+    pub fn with_retry_message(self, retry_message: Option<Message>) -> Self {
+        match self.is_retryable && retry_message.is_some() {
+            true => {
+                let actions = self
+                    .actions
+                    .iter()
+                    .map(|action| match action {
+                        ErrorAction::GoBack => ErrorAction::RetryNow,
+                        _ => *action,
+                    })
+                    .collect();
+
+                Self {
+                    retry_message,
+                    ..Self::new_with_actions(self.message, actions)
+                }
+            }
+            false => self,
+        }
+    }
+
+    fn new_with_actions(message: Option<String>, actions: Vec<ErrorAction>) -> Self {
+        let menu_items = actions.iter().map(|action| action.get_label()).collect();
+
         Self {
-            message: Some(message.into()),
+            message,
+            actions,
+            menu: Menu::new(menu_items),
+            is_retryable: true,
+            retry_message: None,
         }
     }
 }
 
 impl Screen for ErrorScreen {
     fn get_screen_type(&self) -> ScreenType {
-        ScreenType::QueueScreen
+        ScreenType::ErrorScreen
     }
 }
 
 impl Component for ErrorScreen {
     fn view(&mut self, app: &App, frame: &mut Frame) {
+        ScreenBlock::new_with_color("Error", Color::Red).view(app, frame);
+
         let message = match &self.message {
-            Some(message) => {
-                format!(
-                    "Something went wrong!\n\nError: {}\n\nWould you like to try again?\n\n",
-                    message
-                )
-            }
-            None => {
-                format!("Something went wrong!\n\nWould you like to try again?\n\n")
-            }
+            Some(message) => format!("Something went wrong!\n\n{}", message),
+            None => "Something went wrong!".to_string(),
         };
 
-        Prompt::new_with_color(message, Color::Red).view(app, frame);
+        let area = get_centered_rect(80, 90, frame.area());
+        let chunks = Layout::default()
+            .margin(2)
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Max(6), Constraint::Min(10)])
+            .split(area);
+
+        frame.render_widget(
+            create_centered_paragraph(&message, Some(Color::Red)),
+            chunks[0],
+        );
+
+        self.menu.area = Some(chunks[1]);
+        self.menu.view(app, frame);
     }
 
     fn tick(&mut self, _: &mut App) -> AppResult<Option<Message>> {
@@ -61,12 +158,30 @@ impl Component for ErrorScreen {
     }
 
     fn handle_key_press(&mut self, app: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
+        if let Some(message) = self.menu.handle_key_press(app, key)? {
+            return Ok(Some(message));
+        }
+
         match key.code {
-            KeyCode::Char('y') => Ok(Some(Message::GoToPrevScreen)),
-            KeyCode::Char('n') => {
-                app.is_running = false;
-                Ok(None)
-            }
+            KeyCode::Enter => match self.actions[self.menu.current_menu_index] {
+                ErrorAction::RetryNow => match &self.retry_message {
+                    Some(retry_message) => Ok(Some(Message::RetryOnPrevScreen {
+                        message: Box::new(retry_message.clone()),
+                    })),
+                    None => Ok(Some(Message::GoToPrevScreen)),
+                },
+                ErrorAction::GoBack => Ok(Some(Message::GoToPrevScreen)),
+                ErrorAction::PickDevice => {
+                    let new_screen = Box::new(DevicesScreen::default());
+
+                    Ok(Some(Message::ChangeScreen { new_screen }))
+                }
+                ErrorAction::LogIn => Ok(Some(Message::Reauthenticate)),
+                ErrorAction::Quit => {
+                    app.is_running = false;
+                    Ok(None)
+                }
+            },
             _ => Ok(None),
         }
     }
@@ -1,13 +1,24 @@
-use log::debug;
-use ratatui::{crossterm::event::KeyEvent, style::Color, Frame};
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    style::Color,
+    Frame,
+};
 
 use crate::{
-    components::{screen_block::ScreenBlock, Component},
+    components::{
+        screen_block::ScreenBlock,
+        spotify::{library_list::LibraryList, playlist_form::PlaylistForm},
+        tabs::{tab::Tab, tabbed_view::TabbedView},
+        Component,
+    },
     core::{
         app::{App, AppResult},
         config::Config,
         message::Message,
-        spotify::client::SpotifyClient,
+        spotify::{
+            client::SpotifyClient,
+            library::{Library, LibraryType},
+        },
     },
 };
 
@@ -17,11 +28,35 @@ use super::{
 };
 
 #[derive(Clone)]
-pub struct LibraryScreen;
+pub struct LibraryScreen {
+    tabbed_view: TabbedView,
+    playlist_form: Option<PlaylistForm>,
+}
 
 impl Default for LibraryScreen {
     fn default() -> Self {
-        Self
+        let library_types = vec![
+            LibraryType::SavedTracks,
+            LibraryType::SavedAlbums,
+            LibraryType::FollowedArtists,
+            LibraryType::Playlists,
+        ];
+        let mut tabs: Vec<Tab> = vec![];
+
+        for (i, library_type) in library_types.into_iter().enumerate() {
+            let key = KeyCode::Char(char::from(b'1' + i as u8));
+
+            tabs.push(Tab::new(
+                library_type.get_title(),
+                key,
+                Box::new(LibraryList::new(library_type)),
+            ));
+        }
+
+        Self {
+            tabbed_view: TabbedView::new(tabs),
+            playlist_form: None,
+        }
     }
 }
 
@@ -29,23 +64,31 @@ impl Screen for LibraryScreen {
     fn get_screen_type(&self) -> ScreenType {
         ScreenType::LibraryScreen
     }
+
+    fn get_default_key_press_enabled(&self) -> bool {
+        self.playlist_form.is_none()
+    }
 }
 
 impl Component for LibraryScreen {
     fn view(&mut self, app: &App, frame: &mut Frame) {
         ScreenBlock::new_with_color("Library", Color::Green).view(app, frame);
+
+        self.tabbed_view.view(app, frame);
+
+        if let Some(playlist_form) = self.playlist_form.as_mut() {
+            playlist_form.view(app, frame);
+        }
     }
 
     fn tick(&mut self, app: &mut App) -> AppResult<Option<Message>> {
         match app.spotify_client.clone() {
             Some(spotify_client) => {
-                if spotify_client.credentials.is_none() {
+                if !spotify_client.has_credentials() {
                     let new_screen = Box::new(ShowAuthLinkScreen::new(spotify_client.auth_url));
 
                     return Ok(Some(Message::ChangeScreen { new_screen }));
                 }
-
-                Ok(None)
             }
             None => {
                 let config = Config::new()?;
@@ -55,20 +98,53 @@ impl Component for LibraryScreen {
                     Ok(spotify_client) => {
                         app.spotify_client = Some(spotify_client);
 
-                        Ok(None)
+                        return Ok(None);
                     }
                     Err(_) => {
                         let new_screen = Box::new(CreateConfigFormScreen::new(&config));
 
-                        Ok(Some(Message::ChangeScreen { new_screen }))
+                        return Ok(Some(Message::ChangeScreen { new_screen }));
                     }
                 }
             }
         }
+
+        if let Some(playlist_form) = self.playlist_form.as_mut() {
+            playlist_form.tick(app)?;
+        }
+
+        self.tabbed_view.tick(app)
+    }
+
+    fn handle_key_press(&mut self, app: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
+        if let Some(playlist_form) = self.playlist_form.as_mut() {
+            if key.code == KeyCode::Char('q') && !playlist_form.is_typing() {
+                self.playlist_form = None;
+                return Ok(None);
+            }
+
+            let message = playlist_form.handle_key_press(app, key)?;
+
+            if message.is_some() {
+                self.playlist_form = None;
+            }
+
+            return Ok(message);
+        }
+
+        if key.code == KeyCode::Char('n') {
+            self.playlist_form = Some(PlaylistForm::default());
+            return Ok(None);
+        }
+
+        self.tabbed_view.handle_key_press(app, key)
+    }
+
+    fn get_library(&mut self) -> Option<&mut Library> {
+        self.tabbed_view.get_library()
     }
 
-    fn handle_key_press(&mut self, _: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
-        debug!("{:#?}", key);
-        Ok(None)
+    fn get_components(&mut self) -> Vec<&mut dyn Component> {
+        self.tabbed_view.get_components()
     }
 }
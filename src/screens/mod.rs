@@ -1,3 +1,4 @@
+pub mod add_to_playlist;
 pub mod auth;
 pub mod devices;
 pub mod error;
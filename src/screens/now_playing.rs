@@ -20,6 +20,9 @@ use crate::{
 
 use super::{queue::QueueScreen, search::SearchScreen, Screen, ScreenType};
 
+const SEEK_STEP_MS: i64 = 5_000;
+const VOLUME_STEP: i64 = 10;
+
 #[derive(Debug, Clone)]
 pub struct NowPlayingScreen {
     now_playing: NowPlaying,
@@ -50,12 +53,17 @@ impl Component for NowPlayingScreen {
             return;
         }
 
-        let song_string = format!("Song: {}", self.song.name);
+        let song_string = format!("Song: {}{}", self.song.name, self.song.get_saved_marker());
         let mut artist_string = "Artists: ".to_string();
         let album_string = format!("Album: {}", self.song.album_name);
         let progress_string = self.now_playing.get_progress_string();
         let song_length_string = self.song.get_song_length_string();
-        let shuffle_string = self.now_playing.get_shuffle_string();
+        let shuffle_string = format!(
+            "{}    {}    {}",
+            self.now_playing.get_shuffle_string(),
+            self.now_playing.get_repeat_string(),
+            self.now_playing.get_volume_string()
+        );
 
         for (index, value) in self.song.artist_names.iter().enumerate() {
             if index == self.song.artist_names.len() - 1 {
@@ -74,9 +82,10 @@ impl Component for NowPlayingScreen {
             create_right_aligned_paragraph(&song_length_string, Some(Color::Green));
         let shuffle_paragraph = create_centered_paragraph(&shuffle_string, Some(Color::Green));
 
-        let progress_float: f64 = self.now_playing.progress as f64;
-        let song_length_float: f64 = self.song.song_length as f64;
-        let percent: u16 = ((progress_float / song_length_float) * 100.0) as u16;
+        let percent = match self.song.song_length {
+            0 => 0,
+            song_length => (self.now_playing.get_progress() * 100 / song_length).min(100) as u16,
+        };
 
         let progress_bar_gauge = Gauge::default()
             .percent(percent)
@@ -122,13 +131,38 @@ impl Component for NowPlayingScreen {
             return Ok(Some(Message::RefreshSong));
         }
 
-        Ok(Some(Message::RefreshNowPlaying))
+        Ok(None)
     }
 
     fn handle_key_press(&mut self, _: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
         match key.code {
             KeyCode::Char('p') => Ok(Some(Message::PausePlay)),
             KeyCode::Char('s') => Ok(Some(Message::Shuffle)),
+            KeyCode::Char('r') => Ok(Some(Message::CycleRepeat)),
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                Ok(Some(Message::StepVolume { step: VOLUME_STEP }))
+            }
+            KeyCode::Char('-') => Ok(Some(Message::StepVolume { step: -VOLUME_STEP })),
+            KeyCode::Right | KeyCode::Left if !self.song.is_empty() => {
+                let offset_ms = match key.code == KeyCode::Right {
+                    true => SEEK_STEP_MS,
+                    false => -SEEK_STEP_MS,
+                };
+
+                self.now_playing
+                    .nudge_progress(offset_ms, self.song.song_length);
+
+                Ok(Some(Message::SeekRelative { offset_ms }))
+            }
+            KeyCode::Char(digit @ '0'..='9') if !self.song.is_empty() => {
+                let percent = digit.to_digit(10).unwrap_or_default() as u64 * 10;
+                let position_ms = self.song.song_length * percent / 100;
+
+                self.now_playing.set_progress(position_ms);
+
+                Ok(Some(Message::Seek { position_ms }))
+            }
+            KeyCode::Char('f') if !self.song.is_empty() => Ok(Some(Message::ToggleSavedSong)),
             KeyCode::Char('l') => Ok(Some(Message::NextSong)),
             KeyCode::Char('h') => Ok(Some(Message::PrevSong)),
             KeyCode::Char('q') => Ok(Some(Message::ChangeScreen {
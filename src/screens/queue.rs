@@ -1,28 +1,33 @@
-use log::debug;
-use ratatui::{crossterm::event::KeyEvent, style::Color, Frame};
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Alignment, Constraint, Layout},
+    style::Color,
+    Frame,
+};
 
 use crate::{
-    components::{screen_block::ScreenBlock, Component},
+    components::{list::List, loading::Loading, screen_block::ScreenBlock, Component},
     core::{
         app::{App, AppResult},
         config::Config,
         message::Message,
-        spotify::client::SpotifyClient,
+        spotify::{client::SpotifyClient, now_playing::NowPlaying, queue::Queue},
     },
+    widgets::{block::create_titled_block, paragraph::create_centered_paragraph},
 };
 
 use super::{
+    add_to_playlist::AddToPlaylistScreen,
     auth::{create_config::CreateConfigFormScreen, show_link::ShowAuthLinkScreen},
     Screen, ScreenType,
 };
 
-#[derive(Clone)]
-pub struct QueueScreen;
-
-impl Default for QueueScreen {
-    fn default() -> Self {
-        Self
-    }
+#[derive(Clone, Default)]
+pub struct QueueScreen {
+    now_playing: NowPlaying,
+    queue: Queue,
+    queue_song_id: String,
+    list: List,
 }
 
 impl Screen for QueueScreen {
@@ -34,18 +39,60 @@ impl Screen for QueueScreen {
 impl Component for QueueScreen {
     fn view(&mut self, app: &App, frame: &mut Frame) {
         ScreenBlock::new_with_color("Queue", Color::Green).view(app, frame);
+
+        if !self.queue.is_loaded {
+            Loading::default().view(app, frame);
+            return;
+        }
+
+        let currently_playing_string = match &self.queue.currently_playing {
+            Some((name, _)) => format!("Now Playing: {}", name),
+            None => "Nothing is playing".to_string(),
+        };
+
+        let chunks = Layout::default()
+            .margin(3)
+            .constraints(vec![
+                Constraint::Max(1),
+                Constraint::Max(1),
+                Constraint::Min(3),
+            ])
+            .split(frame.area());
+
+        frame.render_widget(
+            create_centered_paragraph(&currently_playing_string, Some(Color::Green)),
+            chunks[0],
+        );
+        frame.render_widget(
+            create_titled_block("Up Next", Alignment::Left, Color::Green),
+            chunks[2],
+        );
+
+        if self.queue.songs.is_empty() {
+            let empty_chunks = Layout::default()
+                .margin(2)
+                .constraints(vec![Constraint::Min(1)])
+                .split(chunks[2]);
+
+            frame.render_widget(
+                create_centered_paragraph("The queue is empty", Some(Color::Green)),
+                empty_chunks[0],
+            );
+            return;
+        }
+
+        self.list.set_area(chunks[2]);
+        self.list.view(app, frame);
     }
 
     fn tick(&mut self, app: &mut App) -> AppResult<Option<Message>> {
         match app.spotify_client.clone() {
             Some(spotify_client) => {
-                if spotify_client.credentials.is_none() {
+                if !spotify_client.has_credentials() {
                     let new_screen = Box::new(ShowAuthLinkScreen::new(spotify_client.auth_url));
 
                     return Ok(Some(Message::ChangeScreen { new_screen }));
                 }
-
-                Ok(None)
             }
             None => {
                 let config = Config::new()?;
@@ -55,20 +102,45 @@ impl Component for QueueScreen {
                     Ok(spotify_client) => {
                         app.spotify_client = Some(spotify_client);
 
-                        Ok(None)
+                        return Ok(None);
                     }
                     Err(_) => {
                         let new_screen = Box::new(CreateConfigFormScreen::new(&config));
 
-                        Ok(Some(Message::ChangeScreen { new_screen }))
+                        return Ok(Some(Message::ChangeScreen { new_screen }));
                     }
                 }
             }
         }
-    }
 
-    fn handle_key_press(&mut self, _: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
-        debug!("{:#?}", key);
+        if self.queue.songs != self.list.items {
+            self.list = List::new(self.queue.songs.clone(), None);
+        }
+
+        if !self.queue.is_loaded || self.now_playing.song_id != self.queue_song_id {
+            self.queue_song_id = self.now_playing.song_id.clone();
+
+            return Ok(Some(Message::RefreshQueue));
+        }
+
         Ok(None)
     }
+
+    fn handle_key_press(&mut self, app: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
+        match key.code {
+            KeyCode::Char('r') => Ok(Some(Message::RefreshQueue)),
+            KeyCode::Char('A') if !self.list.items.is_empty() => Ok(Some(Message::ChangeScreen {
+                new_screen: Box::new(AddToPlaylistScreen::new(self.list.get_active_item().1)),
+            })),
+            _ => self.list.handle_key_press(app, key),
+        }
+    }
+
+    fn get_now_playing(&mut self) -> Option<&mut NowPlaying> {
+        Some(&mut self.now_playing)
+    }
+
+    fn get_queue(&mut self) -> Option<&mut Queue> {
+        Some(&mut self.queue)
+    }
 }
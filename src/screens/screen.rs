@@ -1,7 +1,5 @@
-use std::time::Duration;
-
 use clap::ValueEnum;
-use ratatui::crossterm::event::{self, Event};
+use ratatui::crossterm::event::KeyEvent;
 
 use crate::{
     components::Component,
@@ -21,9 +19,11 @@ pub enum ScreenType {
     NowPlayingScreen,
     ViewArtistScreen,
     ViewAlbumScreen,
+    ViewPlaylistScreen,
     QueueScreen,
     SearchScreen,
     LibraryScreen,
+    AddToPlaylistScreen,
     ErrorScreen,
     DevicesScreen,
 }
@@ -35,24 +35,14 @@ pub trait Screen: ScreenClone + Component {
         true
     }
 
-    fn handle_event(&mut self, app: &mut App) -> AppResult<Option<Message>> {
-        if event::poll(Duration::from_millis(250))? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == event::KeyEventKind::Press {
-                    if self.get_default_key_press_enabled() {
-                        if let Some(message) = app.handle_default_key_press(key)? {
-                            return Ok(Some(message));
-                        }
-                    }
-
-                    let message = self.handle_key_press(app, key)?;
-
-                    return Ok(message);
-                }
+    fn handle_event(&mut self, app: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
+        if self.get_default_key_press_enabled() {
+            if let Some(message) = app.handle_default_key_press(key)? {
+                return Ok(Some(message));
             }
         }
 
-        Ok(None)
+        self.handle_key_press(app, key)
     }
 }
 
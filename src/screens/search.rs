@@ -1,13 +1,29 @@
-use log::debug;
-use ratatui::{crossterm::event::KeyEvent, style::Color, Frame};
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Constraint, Layout},
+    style::Color,
+    Frame,
+};
 
 use crate::{
-    components::{screen_block::ScreenBlock, Component},
+    components::{
+        form::{
+            form::{FormMode, Input},
+            text_input::TextInput,
+        },
+        screen_block::ScreenBlock,
+        spotify::search_results::SearchResults,
+        tabs::{tab::Tab, tabbed_view::TabbedView},
+        Component,
+    },
     core::{
         app::{App, AppResult},
         config::Config,
         message::Message,
-        spotify::client::SpotifyClient,
+        spotify::{
+            client::SpotifyClient,
+            search::{Search, SearchType},
+        },
     },
 };
 
@@ -17,11 +33,60 @@ use super::{
 };
 
 #[derive(Clone)]
-pub struct SearchScreen;
+pub struct SearchScreen {
+    input: TextInput,
+    tabbed_view: TabbedView,
+}
 
 impl Default for SearchScreen {
     fn default() -> Self {
-        Self
+        let mut input = TextInput::new("Search", "", false);
+
+        input.set_is_focused(true);
+        input.set_mode(FormMode::Insert);
+
+        let search_types = vec![
+            SearchType::Track,
+            SearchType::Album,
+            SearchType::Artist,
+            SearchType::Playlist,
+        ];
+        let mut tabs: Vec<Tab> = vec![];
+
+        for (i, search_type) in search_types.into_iter().enumerate() {
+            let key = KeyCode::Char(char::from(b'1' + i as u8));
+
+            tabs.push(Tab::new(
+                search_type.get_title(),
+                key,
+                Box::new(SearchResults::new(search_type)),
+            ));
+        }
+
+        Self {
+            input,
+            tabbed_view: TabbedView::new(tabs),
+        }
+    }
+}
+
+impl SearchScreen {
+    fn submit_query(&mut self) {
+        let query = self.input.value.trim().to_string();
+
+        self.input.set_mode(FormMode::Normal);
+
+        if query.is_empty() {
+            return;
+        }
+
+        for tab in self.tabbed_view.tabs.iter_mut() {
+            if let Some(search) = tab.component.get_search() {
+                if search.query != query {
+                    search.set_query(query.clone());
+                }
+            }
+        }
     }
 }
 
@@ -34,18 +99,27 @@ impl Screen for SearchScreen {
 impl Component for SearchScreen {
     fn view(&mut self, app: &App, frame: &mut Frame) {
         ScreenBlock::new_with_color("Search", Color::Green).view(app, frame);
+
+        let chunks = Layout::default()
+            .margin(2)
+            .constraints(vec![Constraint::Max(3), Constraint::Min(1)])
+            .split(frame.area());
+
+        self.input.set_area(chunks[0]);
+        self.input.view(app, frame);
+
+        self.tabbed_view.set_area(chunks[1]);
+        self.tabbed_view.view(app, frame);
     }
 
     fn tick(&mut self, app: &mut App) -> AppResult<Option<Message>> {
         match app.spotify_client.clone() {
             Some(spotify_client) => {
-                if spotify_client.credentials.is_none() {
+                if !spotify_client.has_credentials() {
                     let new_screen = Box::new(ShowAuthLinkScreen::new(spotify_client.auth_url));
 
                     return Ok(Some(Message::ChangeScreen { new_screen }));
                 }
-
-                Ok(None)
             }
             None => {
                 let config = Config::new()?;
@@ -55,20 +129,54 @@ impl Component for SearchScreen {
                     Ok(spotify_client) => {
                         app.spotify_client = Some(spotify_client);
 
-                        Ok(None)
+                        return Ok(None);
                     }
                     Err(_) => {
                         let new_screen = Box::new(CreateConfigFormScreen::new(&config));
 
-                        Ok(Some(Message::ChangeScreen { new_screen }))
+                        return Ok(Some(Message::ChangeScreen { new_screen }));
                     }
                 }
             }
         }
+
+        self.input.tick(app)?;
+
+        let is_typing = self.input.get_mode() == FormMode::Insert;
+
+        if app.default_key_press_enabled == is_typing {
+            app.default_key_press_enabled = !is_typing;
+        }
+
+        self.tabbed_view.tick(app)
+    }
+
+    fn handle_key_press(&mut self, app: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
+        if self.input.get_mode() == FormMode::Insert {
+            match key.code {
+                KeyCode::Enter => self.submit_query(),
+                KeyCode::Esc => self.input.set_mode(FormMode::Normal),
+                _ => return self.input.handle_key_press(app, key),
+            }
+
+            return Ok(None);
+        }
+
+        match key.code {
+            KeyCode::Char('i') | KeyCode::Char('/') => {
+                self.input.set_mode(FormMode::Insert);
+
+                Ok(None)
+            }
+            _ => self.tabbed_view.handle_key_press(app, key),
+        }
+    }
+
+    fn get_search(&mut self) -> Option<&mut Search> {
+        self.tabbed_view.get_search()
     }
 
-    fn handle_key_press(&mut self, _: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
-        debug!("{:#?}", key);
-        Ok(None)
+    fn get_components(&mut self) -> Vec<&mut dyn Component> {
+        self.tabbed_view.get_components()
     }
 }
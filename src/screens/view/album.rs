@@ -1,5 +1,5 @@
 use ratatui::{
-    crossterm::event::KeyEvent,
+    crossterm::event::{KeyCode, KeyEvent},
     layout::{Constraint, Direction, Layout},
     style::Color,
     Frame,
@@ -26,6 +26,7 @@ pub struct ViewAlbumScreen {
     now_playing: NowPlaying,
     song_list: AlbumSongList,
     info_window: SongInfoWindow,
+    album_id: Option<String>,
 }
 
 impl Default for ViewAlbumScreen {
@@ -34,18 +35,34 @@ impl Default for ViewAlbumScreen {
             now_playing: NowPlaying::default(),
             song_list: AlbumSongList::default(),
             info_window: SongInfoWindow::default(),
+            album_id: None,
         }
     }
 }
 
 impl ViewAlbumScreen {
+    pub fn new(album_id: String) -> Self {
+        Self {
+            album_id: Some(album_id),
+            ..Self::default()
+        }
+    }
+
+    fn get_album_id(&self) -> String {
+        match &self.album_id {
+            Some(album_id) => album_id.clone(),
+            None => self.now_playing.album_id.clone(),
+        }
+    }
+
     fn get_title(&self) -> String {
         let mut title = "Veiw Album".to_string();
 
         if !self.song_list.album.is_empty() {
             title = format!(
-                "{} - {}",
+                "{}{} - {}",
                 self.song_list.album.name,
+                self.song_list.album.get_saved_marker(),
                 self.song_list.album.get_first_artist()
             )
         }
@@ -64,7 +81,7 @@ impl Component for ViewAlbumScreen {
     fn view(&mut self, app: &App, frame: &mut Frame) {
         ScreenBlock::new_with_color(self.get_title(), Color::Green).view(app, frame);
 
-        if self.now_playing.is_empty() {
+        if self.album_id.is_none() && self.now_playing.is_empty() {
             Loading::default().view(app, frame);
             return;
         }
@@ -99,18 +116,29 @@ impl Component for ViewAlbumScreen {
             return Ok(Some(Message::RefreshSong));
         }
 
-        if self.now_playing.album_id != self.song_list.album.id {
-            self.song_list
-                .set_album_id(self.now_playing.album_id.clone());
+        if self.get_album_id() != self.song_list.album.id {
+            self.song_list.set_album_id(self.get_album_id());
 
             return Ok(Some(Message::RefreshAlbum));
         }
 
-        Ok(Some(Message::RefreshNowPlaying))
+        if let Some(song_ids) = self.song_list.get_prefetch_song_ids() {
+            return Ok(Some(Message::PrefetchSongs { song_ids }));
+        }
+
+        Ok(None)
     }
 
     fn handle_key_press(&mut self, app: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
-        self.song_list.handle_key_press(app, key)
+        match key.code {
+            KeyCode::Char('f') if !self.info_window.song.is_empty() => {
+                Ok(Some(Message::ToggleSavedSong))
+            }
+            KeyCode::Char('F') if !self.song_list.album.is_empty() => {
+                Ok(Some(Message::ToggleSavedAlbum))
+            }
+            _ => self.song_list.handle_key_press(app, key),
+        }
     }
 
     fn get_now_playing(&mut self) -> Option<&mut NowPlaying> {
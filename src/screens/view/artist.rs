@@ -29,11 +29,31 @@ pub struct ViewArtistScreen {
 
 impl Default for ViewArtistScreen {
     fn default() -> Self {
-        let top_songs = TopSongs::default();
-        let albums = ArtistAlbums::default();
-        let singles = ArtistSingles::default();
-        let artist_info = ArtistInfo::default();
+        Self::new_with_components(
+            ArtistInfo::default(),
+            TopSongs::default(),
+            ArtistAlbums::default(),
+            ArtistSingles::default(),
+        )
+    }
+}
+
+impl ViewArtistScreen {
+    pub fn new(artist_id: String) -> Self {
+        Self::new_with_components(
+            ArtistInfo::new(artist_id.clone()),
+            TopSongs::new(artist_id.clone()),
+            ArtistAlbums::new(artist_id.clone()),
+            ArtistSingles::new(artist_id),
+        )
+    }
 
+    fn new_with_components(
+        artist_info: ArtistInfo,
+        top_songs: TopSongs,
+        albums: ArtistAlbums,
+        singles: ArtistSingles,
+    ) -> Self {
         let mut tabs: Vec<Tab> = vec![];
         tabs.push(Tab::new(
             "Artist Info",
@@ -56,9 +76,7 @@ impl Default for ViewArtistScreen {
             tabbed_view: TabbedView::new(tabs),
         }
     }
-}
 
-impl ViewArtistScreen {
     fn get_title(&mut self) -> String {
         let mut title = "View Artist".to_string();
 
@@ -108,4 +126,8 @@ impl Component for ViewArtistScreen {
     fn get_album(&mut self) -> Option<&mut Album> {
         self.tabbed_view.get_album()
     }
+
+    fn get_components(&mut self) -> Vec<&mut dyn Component> {
+        self.tabbed_view.get_components()
+    }
 }
@@ -0,0 +1,271 @@
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Color,
+    Frame,
+};
+
+use crate::{
+    components::{
+        list::List, loading::Loading, prompt::Prompt, screen_block::ScreenBlock,
+        spotify::playlist_form::PlaylistForm, Component,
+    },
+    core::{
+        app::{App, AppResult},
+        message::Message,
+        spotify::playlist::Playlist,
+    },
+    screens::{add_to_playlist::AddToPlaylistScreen, Screen, ScreenType},
+    widgets::{
+        block::create_block,
+        paragraph::{create_centered_paragraph, create_left_aligned_paragraph},
+    },
+};
+
+#[derive(Clone)]
+pub struct ViewPlaylistScreen {
+    playlist: Playlist,
+    list: List,
+    playlist_form: Option<PlaylistForm>,
+    pending_removal: Option<usize>,
+}
+
+impl ViewPlaylistScreen {
+    pub fn new(playlist_id: String) -> Self {
+        Self {
+            playlist: Playlist::new(playlist_id),
+            list: List::default(),
+            playlist_form: None,
+            pending_removal: None,
+        }
+    }
+
+    fn get_title(&self) -> String {
+        let mut title = "View Playlist".to_string();
+
+        if !self.playlist.is_empty() {
+            title = format!("{} - {}", self.playlist.name, self.playlist.owner_name)
+        }
+
+        title
+    }
+
+    fn view_info(&self, area: Rect, frame: &mut Frame) {
+        let visibility = match (self.playlist.collaborative, self.playlist.public) {
+            (true, _) => "Collaborative",
+            (false, true) => "Public",
+            (false, false) => "Private",
+        };
+
+        let info_strings = [
+            format!("Playlist: {}", self.playlist.name),
+            format!("Owner: {}", self.playlist.owner_name),
+            format!("Description: {}", self.playlist.description),
+            format!("Followers: {}", self.playlist.followers),
+            format!("Songs: {}", self.playlist.total_songs),
+            format!("Visibility: {}", visibility),
+        ];
+
+        let info_chunks = Layout::default()
+            .margin(1)
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Max(2),
+                Constraint::Max(2),
+                Constraint::Max(4),
+                Constraint::Max(1),
+                Constraint::Max(1),
+                Constraint::Max(1),
+            ])
+            .split(area);
+
+        for (info_string, chunk) in info_strings.iter().zip(info_chunks.iter()) {
+            let paragraph = create_left_aligned_paragraph(info_string, Some(Color::Green));
+
+            frame.render_widget(paragraph, *chunk);
+        }
+    }
+}
+
+impl Screen for ViewPlaylistScreen {
+    fn get_screen_type(&self) -> ScreenType {
+        ScreenType::ViewPlaylistScreen
+    }
+
+    fn get_default_key_press_enabled(&self) -> bool {
+        self.playlist_form.is_none() && self.pending_removal.is_none()
+    }
+}
+
+impl Component for ViewPlaylistScreen {
+    fn view(&mut self, app: &App, frame: &mut Frame) {
+        ScreenBlock::new_with_color(self.get_title(), Color::Green).view(app, frame);
+
+        if !self.playlist.is_loaded {
+            Loading::default().view(app, frame);
+            return;
+        }
+
+        let chunks = Layout::default()
+            .margin(5)
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(frame.area());
+
+        frame.render_widget(create_block(Color::Green), chunks[0]);
+        frame.render_widget(create_block(Color::Green), chunks[1]);
+
+        self.view_info(chunks[1], frame);
+
+        if self.list.items.is_empty() {
+            let paragraph =
+                create_centered_paragraph("This playlist has no songs", Some(Color::Green));
+
+            frame.render_widget(paragraph, chunks[0]);
+            return;
+        }
+
+        self.list.set_area(chunks[0]);
+        self.list.view(app, frame);
+
+        if let Some(position) = self.pending_removal {
+            let (name, _) = self.list.get_active_item();
+            let prompt = match self.playlist.get_song_copies(position) {
+                copies if copies > 1 => format!(
+                    "Remove all {} copies of {} from this playlist?",
+                    copies, name
+                ),
+                _ => format!(
+                    "Remove {} (position {}) from this playlist?",
+                    name,
+                    position + 1
+                ),
+            };
+
+            Prompt::new_with_color(prompt, Color::Red).view(app, frame);
+        }
+
+        if let Some(playlist_form) = self.playlist_form.as_mut() {
+            playlist_form.view(app, frame);
+        }
+    }
+
+    fn tick(&mut self, app: &mut App) -> AppResult<Option<Message>> {
+        if let Some(playlist_form) = self.playlist_form.as_mut() {
+            playlist_form.tick(app)?;
+        }
+
+        if !self.playlist.is_loaded {
+            return Ok(Some(Message::RefreshPlaylist));
+        }
+
+        let songs = self.playlist.get_songs();
+
+        if songs != self.list.items {
+            match songs.len() == self.list.items.len() {
+                true => self.list.items = songs,
+                false => self.list = List::new(songs, None),
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn handle_key_press(&mut self, app: &mut App, key: KeyEvent) -> AppResult<Option<Message>> {
+        if let Some(playlist_form) = self.playlist_form.as_mut() {
+            if key.code == KeyCode::Char('q') && !playlist_form.is_typing() {
+                self.playlist_form = None;
+                return Ok(None);
+            }
+
+            let message = playlist_form.handle_key_press(app, key)?;
+
+            if message.is_some() {
+                self.playlist_form = None;
+            }
+
+            return Ok(message);
+        }
+
+        if let Some(position) = self.pending_removal {
+            return match key.code {
+                KeyCode::Char('y') => {
+                    self.pending_removal = None;
+                    Ok(Some(Message::RemoveFromPlaylist { position }))
+                }
+                KeyCode::Char('n') => {
+                    self.pending_removal = None;
+                    Ok(None)
+                }
+                _ => Ok(None),
+            };
+        }
+
+        match key.code {
+            KeyCode::Char('r') => {
+                self.playlist.is_loaded = false;
+                return Ok(None);
+            }
+            KeyCode::Char('e') if self.playlist.is_loaded => {
+                self.playlist_form = Some(PlaylistForm::edit(&self.playlist));
+                return Ok(None);
+            }
+            _ => {}
+        }
+
+        if let Some(message) = self.list.handle_key_press(app, key)? {
+            return Ok(Some(message));
+        }
+
+        let position = self.list.active_index;
+
+        match key.code {
+            KeyCode::Char('d') if !self.list.items.is_empty() => {
+                self.pending_removal = Some(position);
+                return Ok(None);
+            }
+            KeyCode::Char('J') if position + 1 < self.list.items.len() => {
+                self.list
+                    .handle_key_press(app, KeyEvent::from(KeyCode::Char('j')))?;
+
+                return Ok(Some(Message::MovePlaylistSong {
+                    from: position,
+                    to: position + 1,
+                }));
+            }
+            KeyCode::Char('K') if position > 0 => {
+                self.list
+                    .handle_key_press(app, KeyEvent::from(KeyCode::Char('k')))?;
+
+                return Ok(Some(Message::MovePlaylistSong {
+                    from: position,
+                    to: position - 1,
+                }));
+            }
+            _ => {}
+        }
+
+        let item = match self.playlist.items.get(position) {
+            Some(item) if item.is_playable => item,
+            _ => return Ok(None),
+        };
+
+        match key.code {
+            KeyCode::Enter => Ok(Some(Message::PlaySongOnPlaylist {
+                position: self.list.active_index,
+                playlist_id: self.playlist.id.clone(),
+            })),
+            KeyCode::Char('a') => Ok(Some(Message::AddToQueue {
+                song_id: item.id.clone(),
+            })),
+            KeyCode::Char('A') => Ok(Some(Message::ChangeScreen {
+                new_screen: Box::new(AddToPlaylistScreen::new(item.id.clone())),
+            })),
+            _ => Ok(None),
+        }
+    }
+
+    fn get_playlist(&mut self) -> Option<&mut Playlist> {
+        Some(&mut self.playlist)
+    }
+}
@@ -1,16 +1,39 @@
 use crate::{
-    core::{app::AppResult, message::Message},
-    screens::error::ErrorScreen,
+    core::{app::AppResult, message::Message, spotify::error::SpotifyError},
+    screens::{error::ErrorScreen, home::HomeScreen},
 };
 
 pub fn handle_error<T>(result: AppResult<T>) -> Option<Message> {
+    handle_error_with_retry(result, None)
+}
+
+pub fn handle_error_with_retry<T>(
+    result: AppResult<T>,
+    retry_message: Option<Message>,
+) -> Option<Message> {
     match result {
         Ok(_) => None,
-        Err(error) => {
-            let new_screen = Box::new(ErrorScreen::new(error.to_string()));
+        Err(error) => match error.downcast_ref::<SpotifyError>() {
+            Some(SpotifyError::AuthRevoked) => {
+                let new_screen = Box::new(HomeScreen::default());
+
+                Some(Message::ChangeScreen { new_screen })
+            }
+            Some(spotify_error) => {
+                let new_screen = Box::new(
+                    ErrorScreen::from_spotify_error(spotify_error)
+                        .with_retry_message(retry_message),
+                );
 
-            Some(Message::ChangeScreen { new_screen })
-        }
+                Some(Message::ChangeScreen { new_screen })
+            }
+            None => {
+                let new_screen =
+                    Box::new(ErrorScreen::new(error.to_string()).with_retry_message(retry_message));
+
+                Some(Message::ChangeScreen { new_screen })
+            }
+        },
     }
 }
 
@@ -24,12 +47,6 @@ pub fn throw_no_spotify_client_error() -> Option<Message> {
     Some(Message::ChangeScreen { new_screen })
 }
 
-pub fn throw_no_now_playing_error() -> Option<Message> {
-    let new_screen = Box::new(ErrorScreen::new(not_set_on_screen_message("NowPlaying")));
-
-    Some(Message::ChangeScreen { new_screen })
-}
-
 pub fn throw_no_device_error() -> Option<Message> {
     let new_screen = Box::new(ErrorScreen::new(not_set_on_screen_message("Device")));
 
@@ -53,3 +70,27 @@ pub fn throw_no_artist_error() -> Option<Message> {
 
     Some(Message::ChangeScreen { new_screen })
 }
+
+pub fn throw_no_playlist_error() -> Option<Message> {
+    let new_screen = Box::new(ErrorScreen::new(not_set_on_screen_message("Playlist")));
+
+    Some(Message::ChangeScreen { new_screen })
+}
+
+pub fn throw_no_search_error() -> Option<Message> {
+    let new_screen = Box::new(ErrorScreen::new(not_set_on_screen_message("Search")));
+
+    Some(Message::ChangeScreen { new_screen })
+}
+
+pub fn throw_no_queue_error() -> Option<Message> {
+    let new_screen = Box::new(ErrorScreen::new(not_set_on_screen_message("Queue")));
+
+    Some(Message::ChangeScreen { new_screen })
+}
+
+pub fn throw_no_library_error() -> Option<Message> {
+    let new_screen = Box::new(ErrorScreen::new(not_set_on_screen_message("Library")));
+
+    Some(Message::ChangeScreen { new_screen })
+}
@@ -1,5 +1,6 @@
 pub mod directory;
 pub mod error;
+pub mod random;
 pub mod string;
-pub mod value;
+pub mod time;
 pub mod vec;
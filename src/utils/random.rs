@@ -0,0 +1,9 @@
+use rand::{distributions::Alphanumeric, thread_rng, Rng};
+
+pub fn get_random_string(length: usize) -> String {
+    thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(length)
+        .map(char::from)
+        .collect()
+}
@@ -12,3 +12,39 @@ impl<T: Into<String>> Capitalize for T {
         }
     }
 }
+
+pub fn to_file_name(text: &str) -> String {
+    text.chars()
+        .map(
+            |char| match char.is_ascii_alphanumeric() || char == '-' || char == '.' {
+                true => char,
+                false => '_',
+            },
+        )
+        .collect()
+}
+
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(char),
+        }
+    }
+
+    escaped
+}
+
+pub fn to_script_string(text: &str) -> String {
+    let json = serde_json::to_string(text).unwrap_or_else(|_| "\"\"".to_string());
+
+    json.replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026")
+}
@@ -0,0 +1,73 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub fn milliseconds_to_string(ms: u64) -> String {
+    let total_seconds = ms / 1_000;
+    let minutes = total_seconds / 60;
+    let seconds = total_seconds % 60;
+
+    format!("{}:{:02}", minutes, seconds)
+}
+
+pub fn parse_timestamp(timestamp: &str) -> Option<u64> {
+    let mut seconds: u64 = 0;
+    let parts: Vec<&str> = timestamp.trim().split(':').collect();
+
+    if parts.len() > 3 {
+        return None;
+    }
+
+    for (index, part) in parts.iter().enumerate() {
+        let value = part.parse::<u64>().ok()?;
+
+        if index > 0 && value >= 60 {
+            return None;
+        }
+
+        seconds = seconds.checked_mul(60)?.checked_add(value)?;
+    }
+
+    seconds.checked_mul(1_000)
+}
+
+pub fn get_unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+pub fn get_unix_time_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_timestamps() {
+        assert_eq!(parse_timestamp("90"), Some(90_000));
+        assert_eq!(parse_timestamp("1:30"), Some(90_000));
+        assert_eq!(parse_timestamp(" 0:05 "), Some(5_000));
+        assert_eq!(parse_timestamp("1:02:03"), Some(3_723_000));
+    }
+
+    #[test]
+    fn rejects_invalid_timestamps() {
+        assert_eq!(parse_timestamp(""), None);
+        assert_eq!(parse_timestamp("1:60"), None);
+        assert_eq!(parse_timestamp("1:-5"), None);
+        assert_eq!(parse_timestamp("a:30"), None);
+        assert_eq!(parse_timestamp("1:2:3:4"), None);
+        assert_eq!(parse_timestamp(&u64::MAX.to_string()), None);
+    }
+
+    #[test]
+    fn formats_milliseconds() {
+        assert_eq!(milliseconds_to_string(0), "0:00");
+        assert_eq!(milliseconds_to_string(65_999), "1:05");
+    }
+}
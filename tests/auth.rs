@@ -0,0 +1,141 @@
+mod common;
+
+use std::{fs, path::Path};
+
+use serde_json::{from_value, json, Value};
+use spotify_client_tui::{
+    core::spotify::{client::SpotifyClient, error::SpotifyError},
+    mock::fixtures::Fixtures,
+};
+
+use common::{create_client, get_config, start_mock_server};
+
+fn get_saved_refresh_token(spotify_client: &SpotifyClient) -> String {
+    let data = fs::read_to_string(&spotify_client.credentials_path).unwrap();
+    let credentials: Value = serde_json::from_str(&data).unwrap();
+
+    credentials["refresh_token"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn token_refresh_rotates_and_recovers() {
+    let mut fixtures = Fixtures::load(None).unwrap();
+
+    fixtures.token_expires_in = 30;
+
+    let base_url = start_mock_server(fixtures).await;
+    let mut spotify_client = create_client(&base_url).await;
+
+    assert_eq!(
+        spotify_client.get_auth_header().unwrap(),
+        "Bearer mock-access-token-1"
+    );
+    assert_eq!(
+        get_saved_refresh_token(&spotify_client),
+        "mock-refresh-token-1"
+    );
+
+    spotify_client.get("me").await.unwrap();
+
+    assert_eq!(
+        spotify_client.get_auth_header().unwrap(),
+        "Bearer mock-access-token-2"
+    );
+    assert_eq!(
+        get_saved_refresh_token(&spotify_client),
+        "mock-refresh-token-2"
+    );
+
+    spotify_client.get("me").await.unwrap();
+
+    assert_eq!(
+        spotify_client.get_auth_header().unwrap(),
+        "Bearer mock-access-token-3"
+    );
+    assert_eq!(
+        get_saved_refresh_token(&spotify_client),
+        "mock-refresh-token-3"
+    );
+}
+
+#[tokio::test]
+async fn unauthorized_response_refreshes_token_and_retries() {
+    let mut fixtures = Fixtures::load(None).unwrap();
+
+    fixtures.responses.push(
+        from_value(json!({
+            "method": "GET",
+            "path": "/v1/me",
+            "status": 401,
+            "body": { "error": { "status": 401, "message": "The access token expired" } },
+            "times": 1,
+        }))
+        .unwrap(),
+    );
+
+    let base_url = start_mock_server(fixtures).await;
+    let mut spotify_client = create_client(&base_url).await;
+
+    spotify_client.get("me").await.unwrap();
+
+    assert_eq!(
+        spotify_client.get_auth_header().unwrap(),
+        "Bearer mock-access-token-2"
+    );
+}
+
+#[tokio::test]
+async fn rejected_refresh_token_revokes_auth() {
+    let mut fixtures = Fixtures::load(None).unwrap();
+
+    fixtures.token_expires_in = 30;
+
+    let base_url = start_mock_server(fixtures).await;
+    let mut spotify_client = create_client(&base_url).await;
+
+    let mut fixtures = Fixtures::load(None).unwrap();
+
+    fixtures.responses.push(
+        from_value(json!({
+            "method": "POST",
+            "path": "/api/token",
+            "status": 400,
+            "body": { "error": "invalid_grant", "error_description": "Refresh token revoked" },
+        }))
+        .unwrap(),
+    );
+
+    let revoking_base_url = start_mock_server(fixtures).await;
+
+    spotify_client.config.accounts_url = Some(revoking_base_url);
+
+    let error = spotify_client.get("me").await.unwrap_err();
+
+    assert!(matches!(
+        error.downcast_ref::<SpotifyError>(),
+        Some(SpotifyError::AuthRevoked)
+    ));
+    assert!(!spotify_client.has_credentials());
+}
+
+#[tokio::test]
+async fn credentials_are_scoped_to_accounts_url() {
+    let base_url = start_mock_server(Fixtures::load(None).unwrap()).await;
+    let other_base_url = start_mock_server(Fixtures::load(None).unwrap()).await;
+    let mut spotify_client = create_client(&base_url).await;
+    let mut other_client = create_client(&other_base_url).await;
+
+    assert_ne!(
+        spotify_client.credentials_path,
+        other_client.credentials_path
+    );
+
+    other_client.clear_credentials().unwrap();
+
+    assert!(!Path::new(&other_client.credentials_path).exists());
+    assert!(SpotifyClient::new(get_config(&base_url))
+        .unwrap()
+        .has_credentials());
+
+    spotify_client.clear_credentials().unwrap();
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use serde_json::{json, Value};
+use spotify_client_tui::{
+    core::{config::Config, spotify::client::SpotifyClient},
+    mock::fixtures::Fixtures,
+};
+
+use common::{create_client_from_config, get_config, start_mock_server};
+
+async fn create_persisted_client(base_url: &str) -> SpotifyClient {
+    let config = Config {
+        persist_cache: Some(true),
+        ..get_config(base_url)
+    };
+    let mut spotify_client = create_client_from_config(config).await;
+
+    spotify_client.load_cache().await.unwrap();
+
+    spotify_client
+}
+
+#[tokio::test]
+async fn persisted_cache_is_scoped_to_api_url_and_user() {
+    let fixtures = Fixtures::load(None).unwrap();
+    let album_route = format!("albums/{}", fixtures.albums[0].id);
+    let mut other_user_fixtures = Fixtures::load(None).unwrap();
+
+    other_user_fixtures.user["id"] = json!("someone-else");
+
+    let base_url = start_mock_server(fixtures).await;
+    let other_base_url = start_mock_server(Fixtures::load(None).unwrap()).await;
+    let other_user_base_url = start_mock_server(other_user_fixtures).await;
+
+    let mut spotify_client = create_persisted_client(&base_url).await;
+
+    spotify_client
+        .get_cached_json::<Value>(&album_route)
+        .await
+        .unwrap();
+    spotify_client.cache.save().unwrap();
+
+    let same_session = create_persisted_client(&base_url).await;
+    let other_api = create_persisted_client(&other_base_url).await;
+    let other_user = create_persisted_client(&other_user_base_url).await;
+
+    assert!(same_session.cache.get(&album_route).is_some());
+    assert!(other_api.cache.get(&album_route).is_none());
+    assert!(other_user.cache.get(&album_route).is_none());
+}
@@ -0,0 +1,125 @@
+#![allow(dead_code)]
+
+use std::{env, fs, process, sync::Once};
+
+use serde_json::{from_value, json};
+use spotify_client_tui::{
+    core::{config::Config, spotify::client::SpotifyClient},
+    mock::{fixtures::Fixtures, server::MockServer},
+};
+
+pub const PAGING_ARTIST_ID: &str = "pagingartist0000000001";
+pub const PAGING_ALBUM_ID: &str = "pagingalbum00000000001";
+pub const PAGING_ALBUM_COUNT: usize = 55;
+pub const PAGING_TRACK_COUNT: usize = 60;
+
+static SET_HOME: Once = Once::new();
+
+pub fn get_home_dir() -> String {
+    format!(
+        "{}/spotify-client-tui-tests-{}",
+        env::temp_dir().display(),
+        process::id()
+    )
+}
+
+pub async fn start_mock_server(fixtures: Fixtures) -> String {
+    SET_HOME.call_once(|| {
+        let home_dir = get_home_dir();
+
+        fs::create_dir_all(&home_dir).unwrap();
+        env::set_var("HOME", &home_dir);
+        env::remove_var("SPOTIFY_API_URL");
+        env::remove_var("SPOTIFY_ACCOUNTS_URL");
+        env::remove_var("SPOTIFY_WEB_URL");
+    });
+
+    let server = MockServer::bind("127.0.0.1", 0, fixtures).await.unwrap();
+    let base_url = format!("http://{}", server.local_addr().unwrap());
+
+    tokio::spawn(server.run());
+
+    base_url
+}
+
+pub fn get_config(base_url: &str) -> Config {
+    Config {
+        client_id: Some("mock-client-id".to_string()),
+        redirect_uri: Some("http://127.0.0.1:8888/callback".to_string()),
+        scope: Some("user-read-playback-state user-modify-playback-state".to_string()),
+        api_url: Some(format!("{}/v1", base_url)),
+        accounts_url: Some(base_url.to_string()),
+        web_url: Some(base_url.to_string()),
+        ..Config::default()
+    }
+}
+
+pub async fn create_client(base_url: &str) -> SpotifyClient {
+    create_client_from_config(get_config(base_url)).await
+}
+
+pub async fn create_client_from_config(config: Config) -> SpotifyClient {
+    let mut spotify_client = SpotifyClient::new(config).unwrap();
+
+    spotify_client
+        .set_code_and_access_token("mock-code".to_string())
+        .await
+        .unwrap();
+
+    spotify_client
+}
+
+pub fn get_paging_fixtures() -> Fixtures {
+    let mut fixtures = Fixtures::load(None).unwrap();
+
+    fixtures.artists.push(
+        from_value(json!({
+            "id": PAGING_ARTIST_ID,
+            "name": "The Paginators",
+            "monthly_listeners": 1234,
+        }))
+        .unwrap(),
+    );
+
+    for index in 0..PAGING_ALBUM_COUNT {
+        let album_id = match index {
+            0 => PAGING_ALBUM_ID.to_string(),
+            _ => format!("pagingalbum{:011}", index + 1),
+        };
+        let track_count = match index {
+            0 => PAGING_TRACK_COUNT,
+            _ => 1,
+        };
+        let mut track_ids = vec![];
+
+        for track_index in 0..track_count {
+            let track_id = format!("pagingtrack{:05}{:06}", index + 1, track_index + 1);
+
+            fixtures.tracks.push(
+                from_value(json!({
+                    "id": track_id,
+                    "name": format!("Page Track {}", track_index + 1),
+                    "duration_ms": 180000,
+                    "album": album_id,
+                    "artists": [PAGING_ARTIST_ID],
+                }))
+                .unwrap(),
+            );
+            track_ids.push(track_id);
+        }
+
+        fixtures.albums.push(
+            from_value(json!({
+                "id": album_id,
+                "name": format!("Page Album {}", index + 1),
+                "album_type": "album",
+                "release_date": "2020-01-01",
+                "artists": [PAGING_ARTIST_ID],
+                "tracks": track_ids,
+            }))
+            .unwrap(),
+        );
+    }
+
+    fixtures
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use spotify_client_tui::core::spotify::{
+    album::Album, artist::Artist, client::SpotifyClient, song::Song,
+};
+
+use common::{
+    create_client, get_paging_fixtures, start_mock_server, PAGING_ALBUM_COUNT, PAGING_ALBUM_ID,
+    PAGING_ARTIST_ID, PAGING_TRACK_COUNT,
+};
+
+#[tokio::test]
+async fn album_refresh_collects_every_track_page() {
+    let base_url = start_mock_server(get_paging_fixtures()).await;
+    let mut spotify_client = create_client(&base_url).await;
+    let mut album = Album::new(PAGING_ALBUM_ID.to_string());
+
+    album.refresh(&mut spotify_client).await.unwrap();
+
+    assert_eq!(album.name, "Page Album 1");
+    assert_eq!(album.songs.len(), PAGING_TRACK_COUNT);
+    assert_eq!(album.songs[0].0, "Page Track 1");
+    assert_eq!(
+        album.songs[PAGING_TRACK_COUNT - 1].0,
+        format!("Page Track {}", PAGING_TRACK_COUNT)
+    );
+}
+
+#[tokio::test]
+async fn artist_refresh_collects_every_album_page() {
+    let base_url = start_mock_server(get_paging_fixtures()).await;
+    let mut spotify_client = create_client(&base_url).await;
+    let mut artist = Artist::new(PAGING_ARTIST_ID.to_string());
+
+    artist.refresh(&mut spotify_client).await.unwrap();
+
+    assert_eq!(artist.name, "The Paginators");
+    assert_eq!(artist.albums.len(), PAGING_ALBUM_COUNT);
+    assert!(artist.singles.is_empty());
+    assert_eq!(artist.monthly_listeners, "1,234");
+}
+
+#[tokio::test]
+async fn artist_refresh_reads_fixture_details() {
+    let base_url = start_mock_server(get_paging_fixtures()).await;
+    let mut spotify_client = create_client(&base_url).await;
+    let mut artist = Artist::new("mockartist000000000001".to_string());
+
+    artist.refresh(&mut spotify_client).await.unwrap();
+
+    assert_eq!(artist.name, "The Offline Ensemble");
+    assert_eq!(artist.top_songs.len(), 5);
+    assert_eq!(artist.top_songs[0].0, "Cached Response");
+    assert_eq!(artist.followers, "48,213");
+}
+
+fn get_requests(spotify_client: &SpotifyClient, endpoint: &str) -> u64 {
+    spotify_client
+        .get_endpoint_stats()
+        .into_iter()
+        .find(|(name, _)| name == endpoint)
+        .map(|(_, stats)| stats.requests)
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn song_prefetch_batches_saved_flags() {
+    let base_url = start_mock_server(get_paging_fixtures()).await;
+    let mut spotify_client = create_client(&base_url).await;
+    let song_ids: Vec<String> = (1..=4)
+        .map(|index| format!("mocktrack{:013}", index))
+        .collect();
+
+    Song::prefetch(&mut spotify_client, &song_ids)
+        .await
+        .unwrap();
+
+    let mut songs = vec![];
+
+    for id in &song_ids {
+        let mut song = Song::new(id.clone());
+
+        song.refresh(&mut spotify_client).await.unwrap();
+        songs.push(song);
+    }
+
+    assert_eq!(songs[2].is_saved, Some(true));
+    assert_eq!(songs[0].is_saved, Some(false));
+    assert_eq!(get_requests(&spotify_client, "GET me/tracks/contains"), 1);
+
+    songs[2]
+        .set_saved(&mut spotify_client, false)
+        .await
+        .unwrap();
+
+    let mut song = Song::new(song_ids[2].clone());
+
+    song.refresh(&mut spotify_client).await.unwrap();
+
+    assert_eq!(song.is_saved, Some(false));
+    assert_eq!(get_requests(&spotify_client, "GET me/tracks/contains"), 1);
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use spotify_client_tui::{
+    core::spotify::{
+        client::SpotifyClient, models::playback::PlaybackStateObject, player::SpotifyPlayer,
+    },
+    mock::fixtures::Fixtures,
+};
+
+use common::{create_client, start_mock_server};
+
+async fn get_playback_state(spotify_client: &mut SpotifyClient) -> PlaybackStateObject {
+    spotify_client
+        .get_json::<PlaybackStateObject>("me/player")
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn play_and_pause() {
+    let base_url = start_mock_server(Fixtures::load(None).unwrap()).await;
+    let mut spotify_client = create_client(&base_url).await;
+    let player = SpotifyPlayer::new();
+
+    player
+        .play_context(
+            &mut spotify_client,
+            "spotify:album:mockalbum0000000000002".to_string(),
+        )
+        .await
+        .unwrap();
+
+    let playback_state = get_playback_state(&mut spotify_client).await;
+
+    assert!(playback_state.is_playing);
+    assert_eq!(
+        playback_state.context.map(|context| context.uri),
+        Some("spotify:album:mockalbum0000000000002".to_string())
+    );
+
+    player.toggle_pause_play(&mut spotify_client).await.unwrap();
+
+    assert!(!get_playback_state(&mut spotify_client).await.is_playing);
+
+    player.toggle_pause_play(&mut spotify_client).await.unwrap();
+
+    assert!(get_playback_state(&mut spotify_client).await.is_playing);
+}
+
+#[tokio::test]
+async fn seek() {
+    let base_url = start_mock_server(Fixtures::load(None).unwrap()).await;
+    let mut spotify_client = create_client(&base_url).await;
+    let player = SpotifyPlayer::new();
+
+    player.toggle_pause_play(&mut spotify_client).await.unwrap();
+    player.seek(&mut spotify_client, 42_000).await.unwrap();
+
+    let playback_state = get_playback_state(&mut spotify_client).await;
+
+    assert_eq!(playback_state.progress_ms, Some(42_000));
+
+    player
+        .seek_relative(&mut spotify_client, -50_000)
+        .await
+        .unwrap();
+
+    let playback_state = get_playback_state(&mut spotify_client).await;
+
+    assert_eq!(playback_state.progress_ms, Some(0));
+}
+
+#[tokio::test]
+async fn set_and_step_volume() {
+    let base_url = start_mock_server(Fixtures::load(None).unwrap()).await;
+    let mut spotify_client = create_client(&base_url).await;
+    let player = SpotifyPlayer::new();
+
+    player.set_volume(&mut spotify_client, 40).await.unwrap();
+    player.step_volume(&mut spotify_client, 15).await.unwrap();
+
+    let playback_state = get_playback_state(&mut spotify_client).await;
+
+    assert_eq!(
+        playback_state
+            .device
+            .and_then(|device| device.volume_percent),
+        Some(55)
+    );
+
+    player.set_volume(&mut spotify_client, 150).await.unwrap();
+
+    let playback_state = get_playback_state(&mut spotify_client).await;
+
+    assert_eq!(
+        playback_state
+            .device
+            .and_then(|device| device.volume_percent),
+        Some(100)
+    );
+}
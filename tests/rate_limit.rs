@@ -0,0 +1,79 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use serde_json::{from_value, json};
+use spotify_client_tui::{core::spotify::error::SpotifyError, mock::fixtures::Fixtures};
+
+use common::{create_client, start_mock_server};
+
+fn get_rate_limited_fixtures(path: &str, retry_after: u64) -> Fixtures {
+    let mut fixtures = Fixtures::load(None).unwrap();
+
+    fixtures.responses.push(
+        from_value(json!({
+            "method": "GET",
+            "path": path,
+            "status": 429,
+            "headers": { "retry-after": retry_after.to_string() },
+            "times": 1,
+        }))
+        .unwrap(),
+    );
+
+    fixtures
+}
+
+#[tokio::test]
+async fn retries_after_retry_after_delay() {
+    let fixtures = get_rate_limited_fixtures("/v1/tracks/mocktrack0000000000001", 1);
+    let base_url = start_mock_server(fixtures).await;
+    let mut spotify_client = create_client(&base_url).await;
+    let started_at = Instant::now();
+
+    spotify_client
+        .get("tracks/mocktrack0000000000001")
+        .await
+        .unwrap();
+
+    assert!(started_at.elapsed() >= Duration::from_secs(1));
+
+    let stats = spotify_client.get_endpoint_stats();
+    let (_, track_stats) = stats
+        .iter()
+        .find(|(endpoint, _)| endpoint == "GET tracks/{id}")
+        .unwrap();
+
+    assert_eq!(track_stats.requests, 2);
+    assert_eq!(track_stats.rate_limited, 1);
+    assert_eq!(track_stats.retries, 1);
+    assert_eq!(track_stats.errors, 0);
+}
+
+#[tokio::test]
+async fn long_retry_after_fails_without_waiting() {
+    let fixtures = get_rate_limited_fixtures("/v1/tracks/mocktrack0000000000001", 120);
+    let base_url = start_mock_server(fixtures).await;
+    let mut spotify_client = create_client(&base_url).await;
+    let started_at = Instant::now();
+
+    let error = spotify_client
+        .get("tracks/mocktrack0000000000001")
+        .await
+        .unwrap_err();
+
+    assert!(started_at.elapsed() < Duration::from_secs(5));
+    assert!(matches!(
+        error.downcast_ref::<SpotifyError>(),
+        Some(SpotifyError::RateLimited {
+            retry_after: Some(120)
+        })
+    ));
+
+    let error = spotify_client.get("me").await.unwrap_err();
+
+    assert!(matches!(
+        error.downcast_ref::<SpotifyError>(),
+        Some(SpotifyError::RateLimited { .. })
+    ));
+}